@@ -0,0 +1,63 @@
+use common::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::fs;
+use std::path::Path;
+
+/// The puzzle input from `inputs/`, falling back on a generated input of the puzzle's size.
+fn input(day: u8) -> Option<String> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../inputs/day{}.txt", day));
+    fs::read_to_string(path)
+        .ok()
+        .or_else(|| generator::generate(day, 0, generator::puzzle_size(day)).map(|g| g.input))
+}
+
+fn bench<S: Solution>(c: &mut Criterion, day: u8) {
+    let Some(input) = input(day) else {
+        return;
+    };
+    let Ok(model) = S::parse(&input) else {
+        return;
+    };
+
+    let mut group = c.benchmark_group(format!("day{}", day));
+    group.bench_function("parse", |b| b.iter(|| S::parse(&input)));
+    group.bench_function("part1", |b| b.iter(|| S::part1(&model)));
+    group.bench_function("part2", |b| b.iter(|| S::part2(&model)));
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench::<day1::Day1>(c, 1);
+    bench::<day2::Day2>(c, 2);
+    bench::<day3::Day3>(c, 3);
+    bench::<day4::Day4>(c, 4);
+    bench::<day5::Day5>(c, 5);
+    bench::<day6::Day6>(c, 6);
+    bench::<day7::Day7>(c, 7);
+    bench::<day8::Day8>(c, 8);
+    bench::<day9::Day9>(c, 9);
+    bench::<day10::Day10>(c, 10);
+    bench::<day11::Day11>(c, 11);
+    bench::<day12::Day12>(c, 12);
+    bench::<day13::Day13>(c, 13);
+    bench::<day14::Day14>(c, 14);
+    bench::<day15::Day15>(c, 15);
+    bench::<day16::Day16>(c, 16);
+    bench::<day17::Day17>(c, 17);
+    bench::<day18::Day18>(c, 18);
+    bench::<day19::Day19>(c, 19);
+    bench::<day20::Day20>(c, 20);
+    bench::<day21::Day21>(c, 21);
+    bench::<day22::Day22>(c, 22);
+    bench::<day23::Day23>(c, 23);
+    bench::<day24::Day24>(c, 24);
+    bench::<day25::Day25>(c, 25);
+}
+
+criterion_group! {
+    name = benches;
+    // some days take seconds per run
+    config = Criterion::default().sample_size(10);
+    targets = days
+}
+criterion_main!(benches);
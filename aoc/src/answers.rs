@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// An answer we know to be right for one part of a day, on the input with the given hash.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+}
+
+impl KnownAnswer {
+    /// The answer to record for `part` of `day` on `input`, in its canonical form.
+    pub fn new(day: u8, part: u8, input: &str, answer: &str) -> Self {
+        Self { day, part, input: input_hash(input), answer: canonical(answer) }
+    }
+}
+
+/// How an answer compares with the known one for the same part and input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Check {
+    Ok,
+    /// The known answer, which differs.
+    Mismatch(String),
+    Missing,
+}
+
+/// The form answers are recorded and compared in: the lines of the answer without trailing
+/// whitespace and without the blank lines around them, joined by `\n`. Single-line answers are
+/// only trimmed, while the art some days answer with keeps its rows whatever line endings or
+/// indentation the TOML file gives them.
+pub fn canonical(answer: &str) -> String {
+    let lines = answer.lines().map(str::trim_end).collect::<Vec<_>>();
+    let start = lines.iter().position(|line| !line.is_empty()).unwrap_or(lines.len());
+    let end = lines.iter().rposition(|line| !line.is_empty()).map_or(start, |end| end + 1);
+    let lines = &lines[start..end];
+    match lines {
+        [line] => line.trim_start().to_string(),
+        lines => lines.join("\n"),
+    }
+}
+
+/// The answers of `answers.toml`, one `[[answer]]` table each.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    answers: Vec<KnownAnswer>,
+}
+
+/// SHA-256 of an input file, as printed by `sha256sum`.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+impl Answers {
+    /// Reads the answers from `path`; a missing file holds no answer.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|e| format!("cannot parse {}: {}", path.display(), e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("cannot read {}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = toml::to_string(self).map_err(|e| e.to_string())?;
+        fs::write(path, content).map_err(|e| format!("cannot write {}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, day: u8, part: u8, input: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|known| known.day == day && known.part == part && known.input == input)
+            .map(|known| known.answer.as_str())
+    }
+
+    /// Compares `got` with the known answer for its part and input, if there is one. Answers
+    /// known for other parts or inputs play no part.
+    pub fn check(&self, got: &KnownAnswer) -> Check {
+        match self.get(got.day, got.part, &got.input) {
+            Some(known) if canonical(known) == canonical(&got.answer) => Check::Ok,
+            Some(known) => Check::Mismatch(known.to_string()),
+            None => Check::Missing,
+        }
+    }
+
+    pub fn insert(&mut self, answer: KnownAnswer) {
+        self.answers.push(answer);
+        self.answers.sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+    }
+}
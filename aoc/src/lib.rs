@@ -0,0 +1,5 @@
+pub mod answers;
+pub mod solvers;
+pub mod steering;
+pub mod timing;
+pub mod trace;
@@ -0,0 +1,709 @@
+use aoc::answers::{Answers, Check, KnownAnswer};
+use aoc::solvers::{Animation, Day, Solver, ANIMATIONS, DAYS, SOLVERS};
+use aoc::steering;
+use aoc::timing::Timings;
+use aoc::trace::{self, Trace};
+use clap::builder::PossibleValuesParser;
+use clap::{Parser, Subcommand, ValueEnum};
+use common::cancel::Deadline;
+use common::{Answer, Error, Output, Solution};
+use day1::sonar::{self, Detector};
+use day1::{Day1, Tolerance};
+use day2::trajectory::{self, State};
+use day2::{Day2, Steering};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+use tracing::Level;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2021 solvers")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Number of threads of the parallel solvers; one per CPU by default
+    #[arg(long, global = true, value_parser = clap::value_parser!(u16).range(1..))]
+    threads: Option<u16>,
+    /// Run on a single thread and leave out timings, so that every run prints the same output in
+    /// the same order
+    #[arg(long, global = true, conflicts_with = "threads")]
+    deterministic: bool,
+    /// Record where the solvers spend their time
+    #[arg(long, global = true, value_enum)]
+    trace: Option<TraceFormat>,
+    /// File to write a Chrome trace to
+    #[arg(long, global = true, default_value = "trace.json")]
+    trace_file: PathBuf,
+    /// Most detailed spans to record: `debug` for inner loops, `trace` for every cache lookup
+    #[arg(long, global = true, default_value_t = Level::DEBUG)]
+    trace_level: Level,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solvers of one day, or of all days, against their puzzle input
+    Run {
+        /// Day to run (1-25), or `all`
+        day: Days,
+        /// Part to run; both parts are run when omitted
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, or directory holding one `day<N>.txt` file per day
+        #[arg(long, default_value = "inputs")]
+        input: PathBuf,
+        /// Fail on the first malformed record instead of skipping it
+        #[arg(long)]
+        strict: bool,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Print how long searches are getting on every this many seconds
+        #[arg(long, value_name = "SECONDS")]
+        progress: Option<u64>,
+        /// Directory to save the frontier of long searches to, so that they can be resumed
+        #[arg(long, value_name = "DIRECTORY")]
+        checkpoint: Option<PathBuf>,
+        /// Seconds between two checkpoints
+        #[arg(long, value_name = "SECONDS", default_value_t = 60, requires = "checkpoint")]
+        checkpoint_every: u64,
+        /// Resume searches from their checkpoint when there is one
+        #[arg(long, requires = "checkpoint")]
+        resume: bool,
+        /// Give up on a part after this many seconds
+        #[arg(long, value_name = "SECONDS")]
+        timeout: Option<u64>,
+    },
+    /// Check that the input of a day parses, or print the model the solvers see
+    Parse {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Input file, or directory holding one `day<N>.txt` file per day
+        #[arg(long, default_value = "inputs")]
+        input: PathBuf,
+        /// Fail on the first malformed record instead of skipping it
+        #[arg(long)]
+        strict: bool,
+        /// Print the parsed model as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print the median time taken by the parser and by each part of the selected days
+    Bench {
+        /// Day to time (1-25), or `all`
+        day: Days,
+        /// Input file, or directory holding one `day<N>.txt` file per day
+        #[arg(long, default_value = "inputs")]
+        input: PathBuf,
+        /// Number of runs of each phase
+        #[arg(long, default_value_t = 10)]
+        samples: usize,
+    },
+    /// Check the answers of the selected days against the known answers for their input
+    Verify {
+        /// Day to check (1-25), or `all`
+        day: Days,
+        /// Input file, or directory holding one `day<N>.txt` file per day
+        #[arg(long, default_value = "inputs")]
+        input: PathBuf,
+        /// File holding the known answers
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// Save the answers of the solvers that have no known answer yet
+        #[arg(long)]
+        record: bool,
+        /// Give up on a part after this many seconds
+        #[arg(long, value_name = "SECONDS")]
+        timeout: Option<u64>,
+    },
+    /// Report the runs, drops, increases and outliers of the day 1 sonar sweep
+    Sonar {
+        /// Input file, or directory holding one `day<N>.txt` file per day
+        #[arg(long, default_value = "inputs")]
+        input: PathBuf,
+        /// Sizes of the windows to count increases over
+        #[arg(
+            long = "window",
+            value_delimiter = ',',
+            default_values_t = [1, 3],
+            value_parser = clap::value_parser!(u32).range(1..)
+        )]
+        windows: Vec<u32>,
+        /// Least rise of a window sum over the previous one that counts as an increase
+        #[arg(
+            long,
+            default_value_t = Tolerance::default().min_delta,
+            value_parser = clap::value_parser!(i64).range(1..)
+        )]
+        min_delta: i64,
+        /// Only count an increase after the sum has fallen by this much since the last one
+        #[arg(long, value_parser = clap::value_parser!(i64).range(0..))]
+        hysteresis: Option<i64>,
+        /// How many previous readings an outlier stands out from
+        #[arg(long, default_value_t = Detector::default().history)]
+        history: usize,
+        /// How many scaled median absolute deviations from their median an outlier is
+        #[arg(long, default_value_t = Detector::default().sensitivity)]
+        sensitivity: f64,
+        /// File to write every reading to with what the sweep makes of it, as CSV
+        #[arg(long)]
+        csv: Option<PathBuf>,
+    },
+    /// Follow the day 2 course with a steering model of choice
+    Steer {
+        #[arg(value_parser = PossibleValuesParser::new(steering::models().map(|steering| steering.name)))]
+        model: String,
+        /// Input file, or directory holding one `day<N>.txt` file per day
+        #[arg(long, default_value = "inputs")]
+        input: PathBuf,
+        /// File to write the state after every action to, as CSV
+        #[arg(long)]
+        csv: Option<PathBuf>,
+        /// File to draw the depth profile of the course to, as SVG
+        #[arg(long)]
+        svg: Option<PathBuf>,
+    },
+    /// Print a random input for a day, and the answers to expect when they are known
+    Generate {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Size of the input, whose meaning depends on the day; about the puzzle's by default
+        #[arg(long)]
+        size: Option<usize>,
+    },
+    /// Play the simulation of a day in the terminal, or save its frames to files
+    Show {
+        /// Day to show: 11, 13, 20, 23 or 25
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Input file, or directory holding one `day<N>.txt` file per day
+        #[arg(long, default_value = "inputs")]
+        input: PathBuf,
+        /// Milliseconds between frames in the terminal
+        #[arg(long, default_value_t = 100)]
+        delay: u64,
+        /// Directory to write numbered frame files to, instead of playing them
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Kind of frame files
+        #[arg(long = "as", value_enum, default_value_t = FrameFormat::Text)]
+        frame_format: FrameFormat,
+        /// Size in pixels of one cell of PPM frames
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum TraceFormat {
+    /// Total time by kind of span, and the events, on the standard error
+    Summary,
+    /// Every span and event, for chrome://tracing or Perfetto
+    Chrome,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum FrameFormat {
+    /// Plain text, one character per cell
+    Text,
+    /// Binary PPM images
+    Ppm,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// One `day D part P: answer` line per part
+    Text,
+    /// One JSON object per part, with intermediate results and timings
+    Json,
+}
+
+#[derive(Clone, Copy)]
+enum Days {
+    All,
+    Day(u8),
+}
+
+impl Days {
+    fn contains(self, day: u8) -> bool {
+        match self {
+            Days::All => true,
+            Days::Day(selected) => selected == day,
+        }
+    }
+}
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Days::All);
+        }
+        match s.parse() {
+            Ok(day @ 1..=25) => Ok(Days::Day(day)),
+            _ => Err(format!("expected a day between 1 and 25 or `all`, got `{}`", s)),
+        }
+    }
+}
+
+fn input_path(input: &Path, day: u8) -> PathBuf {
+    if input.is_dir() {
+        input.join(format!("day{}.txt", day))
+    } else {
+        input.to_path_buf()
+    }
+}
+
+fn read_input(input: &Path, day: u8) -> Result<String, String> {
+    let path = input_path(input, day);
+    fs::read_to_string(&path).map_err(|e| format!("cannot read {}: {}", path.display(), e))
+}
+
+fn describe(error: Error, input: &str) -> String {
+    match error {
+        Error::Parse(e) => format!("parse error: {}", e.diagnostic(input)),
+        e => e.to_string(),
+    }
+}
+
+/// Calls `solve`, cancelling the solvers it runs once `timeout` is over.
+fn with_timeout<T>(timeout: Option<Duration>, solve: impl FnOnce() -> T) -> T {
+    match timeout {
+        Some(timeout) => Deadline::after(timeout).run(solve),
+        None => solve(),
+    }
+}
+
+fn run(solver: &Solver, input: &Path, timeout: Option<Duration>) -> Result<Answer, String> {
+    let input = read_input(input, solver.day)?;
+    with_timeout(timeout, || (solver.run)(&input)).map_err(|e| describe(e, &input))
+}
+
+fn parse(day: &Day, input: &Path, json: bool) -> Result<(), String> {
+    let input = read_input(input, day.day)?;
+    if json {
+        let model = (day.model)(&input).map_err(|e| describe(e, &input))?;
+        println!("{}", serde_json::to_string_pretty(&model).expect("values serialize"));
+    } else {
+        (day.parse)(&input).map_err(|e| describe(e, &input))?;
+        println!("day {}: the input parses", day.day);
+    }
+    Ok(())
+}
+
+fn sonar(
+    input: &Path,
+    windows: &[usize],
+    tolerance: Tolerance,
+    detector: Detector,
+    csv: Option<&Path>,
+) -> Result<sonar::Report, String> {
+    let input = read_input(input, 1)?;
+    let readings = Day1::read(&input).map_err(|e| describe(e, &input))?;
+    if let Some(csv) = csv {
+        let file =
+            File::create(csv).map_err(|e| format!("cannot create {}: {}", csv.display(), e))?;
+        sonar::write_csv(sonar::annotate(readings.iter().copied(), detector), BufWriter::new(file))
+            .map_err(|e| format!("cannot write {}: {}", csv.display(), e))?;
+    }
+    Ok(sonar::Report::new(&readings, windows, tolerance, detector))
+}
+
+fn steer(
+    steering: &Steering,
+    input: &Path,
+    csv: Option<&Path>,
+    svg: Option<&Path>,
+) -> Result<Output, String> {
+    let input = read_input(input, 2)?;
+    let actions = Day2::read(&input).map_err(|e| describe(e, &input))?;
+    let trajectory = trajectory::record((steering.new)().as_mut(), &actions);
+    let export = |path: Option<&Path>, write: fn(&[State], BufWriter<File>) -> io::Result<()>| {
+        let Some(path) = path else {
+            return Ok(());
+        };
+        let file =
+            File::create(path).map_err(|e| format!("cannot create {}: {}", path.display(), e))?;
+        write(&trajectory, BufWriter::new(file))
+            .map_err(|e| format!("cannot write {}: {}", path.display(), e))
+    };
+    export(csv, trajectory::write_csv)?;
+    export(svg, trajectory::write_svg)?;
+    let last = trajectory.last().expect("a trajectory starts with the submarine at the surface");
+    Ok(last.output())
+}
+
+fn bench(day: &Day, input: &Path, samples: usize) -> Result<Timings, String> {
+    let input = read_input(input, day.day)?;
+    (day.measure)(&input, samples).map_err(|e| describe(e, &input))
+}
+
+fn verify(
+    solver: &Solver,
+    input: &Path,
+    answers: &Answers,
+    timeout: Option<Duration>,
+) -> Result<(Check, KnownAnswer), String> {
+    let input = read_input(input, solver.day)?;
+    let answer = with_timeout(timeout, || (solver.run)(&input));
+    let answer = answer.map_err(|e| describe(e, &input))?.to_string();
+    let got = KnownAnswer::new(solver.day, solver.part, &input, &answer);
+    Ok((answers.check(&got), got))
+}
+
+fn to_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Number(n) => json!(n),
+        Answer::Text(s) => json!(s),
+    }
+}
+
+/// What `--format json` prints for one part.
+#[derive(Serialize)]
+struct Report {
+    day: u8,
+    part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extra: Option<Map<String, Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timings: Option<ReportTimings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ReportTimings {
+    parse_ns: u128,
+    part_ns: u128,
+}
+
+/// Runs one part for `--format json`, timing it unless the output must not vary between runs.
+fn run_json(
+    solver: &Solver,
+    input: &Path,
+    deterministic: bool,
+    timeout: Option<Duration>,
+) -> Report {
+    let mut report = Report {
+        day: solver.day,
+        part: solver.part,
+        answer: None,
+        extra: None,
+        timings: None,
+        error: None,
+    };
+    let timed = read_input(input, solver.day).and_then(|input| {
+        with_timeout(timeout, || (solver.timed)(&input)).map_err(|e| describe(e, &input))
+    });
+    match timed {
+        Ok(timed) => {
+            let extra = timed.output.extra.iter();
+            report.answer = Some(to_json(&timed.output.answer));
+            report.extra =
+                Some(extra.map(|(name, value)| (name.to_string(), to_json(value))).collect());
+            report.timings = (!deterministic).then_some(ReportTimings {
+                parse_ns: timed.parse.as_nanos(),
+                part_ns: timed.part.as_nanos(),
+            });
+        }
+        Err(e) => report.error = Some(e),
+    }
+    report
+}
+
+fn play(animation: &Animation, input: &Path, delay: Duration) -> Result<(), String> {
+    let input = read_input(input, animation.day)?;
+    let frames = (animation.frames)(&input).map_err(|e| describe(e, &input))?;
+    let mut stdout = std::io::stdout().lock();
+    for frame in frames {
+        // Home the cursor and clear the screen before each frame.
+        write!(stdout, "\x1b[H\x1b[2J{}", frame.to_ansi())
+            .and_then(|()| stdout.flush())
+            .map_err(|e| format!("cannot write frame: {}", e))?;
+        thread::sleep(delay);
+    }
+    Ok(())
+}
+
+/// Writes the frames to `frame-0000.txt`, `frame-0001.txt`... and returns how many there were.
+fn save(
+    animation: &Animation,
+    input: &Path,
+    output: &Path,
+    format: FrameFormat,
+    scale: usize,
+) -> Result<usize, String> {
+    let input = read_input(input, animation.day)?;
+    let frames = (animation.frames)(&input).map_err(|e| describe(e, &input))?;
+    fs::create_dir_all(output).map_err(|e| format!("cannot create {}: {}", output.display(), e))?;
+    let mut count = 0;
+    for (i, frame) in frames.enumerate() {
+        let (extension, contents) = match format {
+            FrameFormat::Text => ("txt", frame.to_text().into_bytes()),
+            FrameFormat::Ppm => ("ppm", frame.to_ppm(scale)),
+        };
+        let path = output.join(format!("frame-{:04}.{}", i, extension));
+        fs::write(&path, contents)
+            .map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Sizes the thread pool every parallel solver runs on.
+fn configure_threads(threads: Option<u16>, deterministic: bool) -> Result<(), String> {
+    let threads = if deterministic { Some(1) } else { threads };
+    let Some(threads) = threads else {
+        return Ok(());
+    };
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads.into())
+        .build_global()
+        .map_err(|e| format!("cannot start {} threads: {}", threads, e))
+}
+
+fn format_duration(duration: Option<Duration>) -> String {
+    duration.map_or_else(|| "-".to_string(), |duration| format!("{:.1?}", duration))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let _trace = cli.trace.map(|format| {
+        let trace = match format {
+            TraceFormat::Summary => Trace::Summary,
+            TraceFormat::Chrome => Trace::Chrome(cli.trace_file.clone()),
+        };
+        trace::start(trace, cli.trace_level)
+    });
+    if let Err(e) = configure_threads(cli.threads, cli.deterministic) {
+        eprintln!("{}", e);
+        return ExitCode::FAILURE;
+    }
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            strict,
+            format,
+            progress,
+            checkpoint,
+            checkpoint_every,
+            resume,
+            timeout,
+        } => {
+            let timeout = timeout.map(Duration::from_secs);
+            common::parse::set_strict(strict);
+            search::checkpoint::configure(search::checkpoint::Settings {
+                progress: progress.map(Duration::from_secs),
+                directory: checkpoint,
+                every: Duration::from_secs(checkpoint_every),
+                resume,
+            });
+            let solvers = SOLVERS
+                .iter()
+                .filter(|solver| day.contains(solver.day))
+                .filter(|solver| part.is_none_or(|part| solver.part == part))
+                .collect::<Vec<_>>();
+
+            if solvers.is_empty() {
+                eprintln!("no solver for this day and part");
+                return ExitCode::FAILURE;
+            }
+
+            let mut failed = false;
+            for solver in solvers {
+                match format {
+                    Format::Text => match run(solver, &input, timeout) {
+                        Ok(output) => {
+                            println!("day {} part {}: {}", solver.day, solver.part, output)
+                        }
+                        Err(e) => {
+                            failed = true;
+                            eprintln!("day {} part {}: error: {}", solver.day, solver.part, e)
+                        }
+                    },
+                    Format::Json => {
+                        let report = run_json(solver, &input, cli.deterministic, timeout);
+                        failed |= report.error.is_some();
+                        println!("{}", serde_json::to_string(&report).expect("reports serialize"))
+                    }
+                }
+            }
+
+            if failed {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Command::Parse { day, input, strict, json } => {
+            common::parse::set_strict(strict);
+            let entry = DAYS.iter().find(|entry| entry.day == day).expect("every day has a parser");
+            match parse(entry, &input, json) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(e) => {
+                    eprintln!("day {}: error: {}", day, e);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Bench { day, input, samples } => {
+            let mut failed = false;
+            println!("{:>3} {:>12} {:>12} {:>12}", "day", "parse", "part 1", "part 2");
+            for entry in DAYS.iter().filter(|entry| day.contains(entry.day)) {
+                match bench(entry, &input, samples) {
+                    Ok(timings) => println!(
+                        "{:>3} {:>12} {:>12} {:>12}",
+                        entry.day,
+                        format_duration(Some(timings.parse)),
+                        format_duration(timings.part1),
+                        format_duration(timings.part2)
+                    ),
+                    Err(e) => {
+                        failed = true;
+                        eprintln!("day {}: error: {}", entry.day, e)
+                    }
+                }
+            }
+
+            if failed {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Command::Verify { day, input, answers: path, record, timeout } => {
+            let timeout = timeout.map(Duration::from_secs);
+            let mut answers = match Answers::load(&path) {
+                Ok(answers) => answers,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let (mut ok, mut mismatches, mut missing, mut recorded, mut errors) = (0, 0, 0, 0, 0);
+            for solver in SOLVERS.iter().filter(|solver| day.contains(solver.day)) {
+                let name = format!("day {} part {}", solver.day, solver.part);
+                match verify(solver, &input, &answers, timeout) {
+                    Ok((Check::Ok, _)) => {
+                        ok += 1;
+                        println!("{}: ok", name)
+                    }
+                    Ok((Check::Mismatch(expected), got)) => {
+                        mismatches += 1;
+                        println!("{}: mismatch: expected {}, got {}", name, expected, got.answer)
+                    }
+                    Ok((Check::Missing, got)) if record => {
+                        recorded += 1;
+                        println!("{}: recorded {}", name, got.answer);
+                        answers.insert(got);
+                    }
+                    Ok((Check::Missing, got)) => {
+                        missing += 1;
+                        println!("{}: missing answer (got {})", name, got.answer)
+                    }
+                    Err(e) => {
+                        errors += 1;
+                        eprintln!("{}: error: {}", name, e)
+                    }
+                }
+            }
+
+            if record {
+                if let Err(e) = answers.save(&path) {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+
+            println!(
+                "{} ok, {} mismatched, {} missing, {} recorded, {} failed",
+                ok, mismatches, missing, recorded, errors
+            );
+            if mismatches > 0 || errors > 0 {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Command::Sonar { input, windows, min_delta, hysteresis, history, sensitivity, csv } => {
+            let windows = windows.into_iter().map(|window| window as usize).collect::<Vec<_>>();
+            let tolerance = Tolerance { min_delta, hysteresis };
+            let detector = Detector { history, sensitivity };
+            match sonar(&input, &windows, tolerance, detector, csv.as_deref()) {
+                Ok(report) => {
+                    print!("{}", report);
+                    ExitCode::SUCCESS
+                }
+                Err(e) => {
+                    eprintln!("day 1: error: {}", e);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Steer { model, input, csv, svg } => {
+            match steer(
+                steering::find(&model).expect("clap checks the model"),
+                &input,
+                csv.as_deref(),
+                svg.as_deref(),
+            ) {
+                Ok(output) => {
+                    let extra =
+                        output.extra.iter().map(|(name, value)| format!("{} {}", name, value));
+                    println!("{} ({})", output.answer, extra.collect::<Vec<_>>().join(", "));
+                    ExitCode::SUCCESS
+                }
+                Err(e) => {
+                    eprintln!("day 2: error: {}", e);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Generate { day, seed, size } => {
+            let size = size.unwrap_or_else(|| generator::puzzle_size(day));
+            let Some(generated) = generator::generate(day, seed, size) else {
+                eprintln!("no generator for day {}", day);
+                return ExitCode::FAILURE;
+            };
+            print!("{}", generated.input);
+            for part in 1..=2 {
+                if let Some(answer) = generated.answer(part) {
+                    eprintln!("part {}: {}", part, answer);
+                }
+            }
+            ExitCode::SUCCESS
+        }
+        Command::Show { day, input, delay, output, frame_format, scale } => {
+            let Some(animation) = ANIMATIONS.iter().find(|animation| animation.day == day) else {
+                eprintln!("no simulation to show for day {}", day);
+                return ExitCode::FAILURE;
+            };
+            let shown = match &output {
+                Some(output) => save(animation, &input, output, frame_format, scale).map(|count| {
+                    println!("wrote {} frames to {}", count, output.display());
+                }),
+                None => play(animation, &input, Duration::from_millis(delay)),
+            };
+            match shown {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(e) => {
+                    eprintln!("day {}: error: {}", day, e);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use crate::timing::{measure, timed, Timed, Timings};
+use common::{Answer, Result, Solution};
+use day11::Day11;
+use day13::Day13;
+use day20::Day20;
+use day23::Day23;
+use day25::Day25;
+use grid::Frame;
+use serde::Serialize;
+use serde_json::Value;
+
+pub struct Solver {
+    pub day: u8,
+    pub part: u8,
+    pub run: fn(&str) -> Result<Answer>,
+    /// Like `run`, with intermediate results and timings.
+    pub timed: fn(&str) -> Result<Timed>,
+}
+
+pub const SOLVERS: &[Solver] = &[
+    Solver { day: 1, part: 1, run: <day1::Day1>::solve_part1, timed: timed::<day1::Day1, 1> },
+    Solver { day: 1, part: 2, run: <day1::Day1>::solve_part2, timed: timed::<day1::Day1, 2> },
+    Solver { day: 2, part: 1, run: <day2::Day2>::solve_part1, timed: timed::<day2::Day2, 1> },
+    Solver { day: 2, part: 2, run: <day2::Day2>::solve_part2, timed: timed::<day2::Day2, 2> },
+    Solver { day: 3, part: 1, run: <day3::Day3>::solve_part1, timed: timed::<day3::Day3, 1> },
+    Solver { day: 3, part: 2, run: <day3::Day3>::solve_part2, timed: timed::<day3::Day3, 2> },
+    Solver { day: 4, part: 1, run: <day4::Day4>::solve_part1, timed: timed::<day4::Day4, 1> },
+    Solver { day: 4, part: 2, run: <day4::Day4>::solve_part2, timed: timed::<day4::Day4, 2> },
+    Solver { day: 5, part: 1, run: <day5::Day5>::solve_part1, timed: timed::<day5::Day5, 1> },
+    Solver { day: 5, part: 2, run: <day5::Day5>::solve_part2, timed: timed::<day5::Day5, 2> },
+    Solver { day: 6, part: 1, run: <day6::Day6>::solve_part1, timed: timed::<day6::Day6, 1> },
+    Solver { day: 6, part: 2, run: <day6::Day6>::solve_part2, timed: timed::<day6::Day6, 2> },
+    Solver { day: 7, part: 1, run: <day7::Day7>::solve_part1, timed: timed::<day7::Day7, 1> },
+    Solver { day: 7, part: 2, run: <day7::Day7>::solve_part2, timed: timed::<day7::Day7, 2> },
+    Solver { day: 8, part: 1, run: <day8::Day8>::solve_part1, timed: timed::<day8::Day8, 1> },
+    Solver { day: 8, part: 2, run: <day8::Day8>::solve_part2, timed: timed::<day8::Day8, 2> },
+    Solver { day: 9, part: 1, run: <day9::Day9>::solve_part1, timed: timed::<day9::Day9, 1> },
+    Solver { day: 9, part: 2, run: <day9::Day9>::solve_part2, timed: timed::<day9::Day9, 2> },
+    Solver { day: 10, part: 1, run: <day10::Day10>::solve_part1, timed: timed::<day10::Day10, 1> },
+    Solver { day: 10, part: 2, run: <day10::Day10>::solve_part2, timed: timed::<day10::Day10, 2> },
+    Solver { day: 11, part: 1, run: <day11::Day11>::solve_part1, timed: timed::<day11::Day11, 1> },
+    Solver { day: 11, part: 2, run: <day11::Day11>::solve_part2, timed: timed::<day11::Day11, 2> },
+    Solver { day: 12, part: 1, run: <day12::Day12>::solve_part1, timed: timed::<day12::Day12, 1> },
+    Solver { day: 12, part: 2, run: <day12::Day12>::solve_part2, timed: timed::<day12::Day12, 2> },
+    Solver { day: 13, part: 1, run: <day13::Day13>::solve_part1, timed: timed::<day13::Day13, 1> },
+    Solver { day: 13, part: 2, run: <day13::Day13>::solve_part2, timed: timed::<day13::Day13, 2> },
+    Solver { day: 14, part: 1, run: <day14::Day14>::solve_part1, timed: timed::<day14::Day14, 1> },
+    Solver { day: 14, part: 2, run: <day14::Day14>::solve_part2, timed: timed::<day14::Day14, 2> },
+    Solver { day: 15, part: 1, run: <day15::Day15>::solve_part1, timed: timed::<day15::Day15, 1> },
+    Solver { day: 15, part: 2, run: <day15::Day15>::solve_part2, timed: timed::<day15::Day15, 2> },
+    Solver { day: 16, part: 1, run: <day16::Day16>::solve_part1, timed: timed::<day16::Day16, 1> },
+    Solver { day: 16, part: 2, run: <day16::Day16>::solve_part2, timed: timed::<day16::Day16, 2> },
+    Solver { day: 17, part: 1, run: <day17::Day17>::solve_part1, timed: timed::<day17::Day17, 1> },
+    Solver { day: 17, part: 2, run: <day17::Day17>::solve_part2, timed: timed::<day17::Day17, 2> },
+    Solver { day: 18, part: 1, run: <day18::Day18>::solve_part1, timed: timed::<day18::Day18, 1> },
+    Solver { day: 18, part: 2, run: <day18::Day18>::solve_part2, timed: timed::<day18::Day18, 2> },
+    Solver { day: 19, part: 1, run: <day19::Day19>::solve_part1, timed: timed::<day19::Day19, 1> },
+    Solver { day: 19, part: 2, run: <day19::Day19>::solve_part2, timed: timed::<day19::Day19, 2> },
+    Solver { day: 20, part: 1, run: <day20::Day20>::solve_part1, timed: timed::<day20::Day20, 1> },
+    Solver { day: 20, part: 2, run: <day20::Day20>::solve_part2, timed: timed::<day20::Day20, 2> },
+    Solver { day: 21, part: 1, run: <day21::Day21>::solve_part1, timed: timed::<day21::Day21, 1> },
+    Solver { day: 21, part: 2, run: <day21::Day21>::solve_part2, timed: timed::<day21::Day21, 2> },
+    Solver { day: 22, part: 1, run: <day22::Day22>::solve_part1, timed: timed::<day22::Day22, 1> },
+    Solver { day: 22, part: 2, run: <day22::Day22>::solve_part2, timed: timed::<day22::Day22, 2> },
+    Solver { day: 23, part: 1, run: <day23::Day23>::solve_part1, timed: timed::<day23::Day23, 1> },
+    Solver { day: 23, part: 2, run: <day23::Day23>::solve_part2, timed: timed::<day23::Day23, 2> },
+    Solver { day: 24, part: 1, run: <day24::Day24>::solve_part1, timed: timed::<day24::Day24, 1> },
+    Solver { day: 24, part: 2, run: <day24::Day24>::solve_part2, timed: timed::<day24::Day24, 2> },
+    Solver { day: 25, part: 1, run: <day25::Day25>::solve_part1, timed: timed::<day25::Day25, 1> },
+];
+
+pub struct Day {
+    pub day: u8,
+    /// Only parses the input, for checking it.
+    pub parse: fn(&str) -> Result<()>,
+    /// Parses the input into the model the parts solve, as JSON.
+    pub model: fn(&str) -> Result<Value>,
+    pub measure: fn(&str, usize) -> Result<Timings>,
+}
+
+fn parse<S: Solution>(input: &str) -> Result<()> {
+    S::read(input).map(drop)
+}
+
+fn model<S: Solution<Model: Serialize>>(input: &str) -> Result<Value> {
+    let model = S::read(input)?;
+    Ok(serde_json::to_value(model).expect("the models have no maps with keys other than strings"))
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        parse: parse::<day1::Day1>,
+        model: model::<day1::Day1>,
+        measure: measure::<day1::Day1>,
+    },
+    Day {
+        day: 2,
+        parse: parse::<day2::Day2>,
+        model: model::<day2::Day2>,
+        measure: measure::<day2::Day2>,
+    },
+    Day {
+        day: 3,
+        parse: parse::<day3::Day3>,
+        model: model::<day3::Day3>,
+        measure: measure::<day3::Day3>,
+    },
+    Day {
+        day: 4,
+        parse: parse::<day4::Day4>,
+        model: model::<day4::Day4>,
+        measure: measure::<day4::Day4>,
+    },
+    Day {
+        day: 5,
+        parse: parse::<day5::Day5>,
+        model: model::<day5::Day5>,
+        measure: measure::<day5::Day5>,
+    },
+    Day {
+        day: 6,
+        parse: parse::<day6::Day6>,
+        model: model::<day6::Day6>,
+        measure: measure::<day6::Day6>,
+    },
+    Day {
+        day: 7,
+        parse: parse::<day7::Day7>,
+        model: model::<day7::Day7>,
+        measure: measure::<day7::Day7>,
+    },
+    Day {
+        day: 8,
+        parse: parse::<day8::Day8>,
+        model: model::<day8::Day8>,
+        measure: measure::<day8::Day8>,
+    },
+    Day {
+        day: 9,
+        parse: parse::<day9::Day9>,
+        model: model::<day9::Day9>,
+        measure: measure::<day9::Day9>,
+    },
+    Day {
+        day: 10,
+        parse: parse::<day10::Day10>,
+        model: model::<day10::Day10>,
+        measure: measure::<day10::Day10>,
+    },
+    Day {
+        day: 11,
+        parse: parse::<day11::Day11>,
+        model: model::<day11::Day11>,
+        measure: measure::<day11::Day11>,
+    },
+    Day {
+        day: 12,
+        parse: parse::<day12::Day12>,
+        model: model::<day12::Day12>,
+        measure: measure::<day12::Day12>,
+    },
+    Day {
+        day: 13,
+        parse: parse::<day13::Day13>,
+        model: model::<day13::Day13>,
+        measure: measure::<day13::Day13>,
+    },
+    Day {
+        day: 14,
+        parse: parse::<day14::Day14>,
+        model: model::<day14::Day14>,
+        measure: measure::<day14::Day14>,
+    },
+    Day {
+        day: 15,
+        parse: parse::<day15::Day15>,
+        model: model::<day15::Day15>,
+        measure: measure::<day15::Day15>,
+    },
+    Day {
+        day: 16,
+        parse: parse::<day16::Day16>,
+        model: model::<day16::Day16>,
+        measure: measure::<day16::Day16>,
+    },
+    Day {
+        day: 17,
+        parse: parse::<day17::Day17>,
+        model: model::<day17::Day17>,
+        measure: measure::<day17::Day17>,
+    },
+    Day {
+        day: 18,
+        parse: parse::<day18::Day18>,
+        model: model::<day18::Day18>,
+        measure: measure::<day18::Day18>,
+    },
+    Day {
+        day: 19,
+        parse: parse::<day19::Day19>,
+        model: model::<day19::Day19>,
+        measure: measure::<day19::Day19>,
+    },
+    Day {
+        day: 20,
+        parse: parse::<day20::Day20>,
+        model: model::<day20::Day20>,
+        measure: measure::<day20::Day20>,
+    },
+    Day {
+        day: 21,
+        parse: parse::<day21::Day21>,
+        model: model::<day21::Day21>,
+        measure: measure::<day21::Day21>,
+    },
+    Day {
+        day: 22,
+        parse: parse::<day22::Day22>,
+        model: model::<day22::Day22>,
+        measure: measure::<day22::Day22>,
+    },
+    Day {
+        day: 23,
+        parse: parse::<day23::Day23>,
+        model: model::<day23::Day23>,
+        measure: measure::<day23::Day23>,
+    },
+    Day {
+        day: 24,
+        parse: parse::<day24::Day24>,
+        model: model::<day24::Day24>,
+        measure: measure::<day24::Day24>,
+    },
+    Day {
+        day: 25,
+        parse: parse::<day25::Day25>,
+        model: model::<day25::Day25>,
+        measure: measure::<day25::Day25>,
+    },
+];
+
+/// A day whose simulation can be watched with `aoc show`.
+pub struct Animation {
+    pub day: u8,
+    pub frames: fn(&str) -> Result<Box<dyn Iterator<Item = Frame>>>,
+}
+
+pub const ANIMATIONS: &[Animation] = &[
+    Animation { day: 11, frames: |input| Ok(Box::new(Day11::frames(&Day11::read(input)?))) },
+    Animation { day: 13, frames: |input| Ok(Box::new(Day13::frames(&Day13::read(input)?)?)) },
+    Animation { day: 20, frames: |input| Ok(Box::new(Day20::frames(&Day20::read(input)?))) },
+    Animation { day: 23, frames: |input| Ok(Box::new(Day23::frames(&Day23::read(input)?)?)) },
+    Animation { day: 25, frames: |input| Ok(Box::new(Day25::frames(&Day25::read(input)?))) },
+];
@@ -0,0 +1,42 @@
+//! The steering models `aoc steer` picks from by name: those of day 2, then the ones registered
+//! here.
+
+use day2::{Action, Aim, Steering, SteeringModel};
+
+/// Aim that loses half its value, rounding towards zero, each time the submarine goes forward.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Drag(pub Aim);
+
+impl SteeringModel for Drag {
+    fn steer(&mut self, action: &Action) {
+        self.0.steer(action);
+        if let Action::Forward(_) = action {
+            self.0.aim -= self.0.aim / 2;
+        }
+    }
+
+    fn position(&self) -> i32 {
+        self.0.position
+    }
+
+    fn depth(&self) -> i32 {
+        self.0.depth
+    }
+
+    fn aim(&self) -> Option<i32> {
+        Some(self.0.aim)
+    }
+}
+
+/// The models from outside of day 2. A new model is registered by adding it here, under the name
+/// `aoc steer` picks it by.
+pub const REGISTERED: &[Steering] = &[Steering { name: "drag", new: || Box::<Drag>::default() }];
+
+/// Every model `aoc steer` knows, those of day 2 first.
+pub fn models() -> impl Iterator<Item = &'static Steering> {
+    day2::STEERING.iter().chain(REGISTERED)
+}
+
+pub fn find(name: &str) -> Option<&'static Steering> {
+    models().find(|steering| steering.name == name)
+}
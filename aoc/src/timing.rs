@@ -0,0 +1,60 @@
+use common::{trace, Answer, Error, Output, Result, Solution};
+use std::time::{Duration, Instant};
+
+/// Median durations of each phase of a day, `None` for a part without a solution.
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+fn median(samples: usize, mut f: impl FnMut() -> Result<()>) -> Result<Duration> {
+    let mut durations = (0..samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            f()?;
+            Ok(start.elapsed())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    durations.sort();
+    Ok(durations[durations.len() / 2])
+}
+
+/// Times `samples` runs of the parser and of both parts on `input`.
+pub fn measure<S: Solution>(input: &str, samples: usize) -> Result<Timings> {
+    let parse = median(samples, || S::read(input).map(drop))?;
+    let model = S::read(input)?;
+    let part = |number: u8, part: fn(&S::Model) -> Result<Answer>| {
+        let span = trace::solve_span::<S>(number);
+        match median(samples, || span.in_scope(|| part(&model).map(drop))) {
+            Ok(duration) => Ok(Some(duration)),
+            Err(Error::NoSolution(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    };
+
+    Ok(Timings { parse, part1: part(1, S::part1)?, part2: part(2, S::part2)? })
+}
+
+/// One part run once on an input, with how long its parser and the part itself took.
+pub struct Timed {
+    pub output: Output,
+    pub parse: Duration,
+    pub part: Duration,
+}
+
+pub fn timed<S: Solution, const PART: u8>(input: &str) -> Result<Timed> {
+    let start = Instant::now();
+    let model = S::read(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let output = trace::solve_span::<S>(PART).in_scope(|| {
+        if PART == 1 {
+            S::part1_output(&model)
+        } else {
+            S::part2_output(&model)
+        }
+    })?;
+    Ok(Timed { output, parse, part: start.elapsed() })
+}
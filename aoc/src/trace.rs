@@ -0,0 +1,162 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Debug, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Level, Subscriber};
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::Layer;
+
+/// Where the spans and events of the solvers go.
+pub enum Trace {
+    /// A table of how long each kind of span took in total, printed to the standard error.
+    Summary,
+    /// A file for `chrome://tracing` or Perfetto.
+    Chrome(PathBuf),
+}
+
+/// Records the spans and events up to `level` until dropped, when it writes them out.
+pub fn start(trace: Trace, level: Level) -> Guard {
+    let registry = tracing_subscriber::registry().with(LevelFilter::from_level(level));
+    match trace {
+        Trace::Summary => {
+            let summary = Arc::new(Mutex::new(Summary::default()));
+            registry.with(SummaryLayer(summary.clone())).init();
+            Guard::Summary(summary)
+        }
+        Trace::Chrome(path) => {
+            let (layer, guard) = ChromeLayerBuilder::new().file(path).include_args(true).build();
+            registry.with(layer).init();
+            Guard::Chrome(guard)
+        }
+    }
+}
+
+pub enum Guard {
+    Summary(Arc<Mutex<Summary>>),
+    Chrome(FlushGuard),
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        if let Guard::Summary(summary) = self {
+            eprint!("{}", summary.lock().unwrap());
+        }
+    }
+}
+
+/// How many times a kind of span was entered and for how long in total.
+#[derive(Default)]
+struct Totals {
+    count: u64,
+    busy: Duration,
+}
+
+/// Totals by span, named after their ancestors as in `solve{solver=Day19 part=1} > align`, and
+/// the events in the order they came.
+#[derive(Default)]
+pub struct Summary {
+    spans: BTreeMap<String, Totals>,
+    events: Vec<String>,
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.spans.keys().map(String::len).max().unwrap_or(0).max(4);
+        writeln!(f, "{:width$} {:>8} {:>12}", "span", "count", "total")?;
+        for (name, totals) in &self.spans {
+            let total = format!("{:.1?}", totals.busy);
+            writeln!(f, "{:width$} {:>8} {:>12}", name, totals.count, total)?;
+        }
+        for event in &self.events {
+            writeln!(f, "{}", event)?;
+        }
+        Ok(())
+    }
+}
+
+/// What the summary keeps of a span while it is open.
+struct Timing {
+    name: String,
+    entered: Option<Instant>,
+    busy: Duration,
+}
+
+struct SummaryLayer(Arc<Mutex<Summary>>);
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for SummaryLayer {
+    fn on_new_span(&self, attributes: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let span = ctx.span(id).expect("a new span is registered");
+        // Only the outermost spans are told apart by their fields, so that the spans of inner
+        // loops add up.
+        let name = match span.parent() {
+            Some(parent) => {
+                let extensions = parent.extensions();
+                let parent = extensions.get::<Timing>().map_or("", |timing| &timing.name);
+                format!("{} > {}", parent, span.name())
+            }
+            None => {
+                let mut fields = Fields::default();
+                attributes.record(&mut fields);
+                format!("{}{{{}}}", span.name(), fields.0.trim_start())
+            }
+        };
+        span.extensions_mut().insert(Timing { name, entered: None, busy: Duration::ZERO });
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(timing) = ctx.span(id).unwrap().extensions_mut().get_mut::<Timing>() {
+            timing.entered = Some(Instant::now());
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(timing) = ctx.span(id).unwrap().extensions_mut().get_mut::<Timing>() {
+            timing.busy +=
+                timing.entered.take().map_or(Duration::ZERO, |entered| entered.elapsed());
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        if let Some(timing) = ctx.span(&id).unwrap().extensions_mut().remove::<Timing>() {
+            let mut summary = self.0.lock().unwrap();
+            let totals = summary.spans.entry(timing.name).or_default();
+            totals.count += 1;
+            totals.busy += timing.busy;
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        let span = ctx.event_span(event);
+        let extensions = span.as_ref().map(|span| span.extensions());
+        let name = extensions.as_ref().and_then(|extensions| extensions.get::<Timing>());
+        let name = name.map_or("", |timing| &timing.name);
+        self.0.lock().unwrap().events.push(format!("{}:{}", name, fields.0));
+    }
+}
+
+/// The fields of a span or an event as ` name=value` pairs, the message first and bare.
+#[derive(Default)]
+struct Fields(String);
+
+impl Visit for Fields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record_debug(field, &format_args!("{}", value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.0.insert_str(0, &format!(" {:?}", value));
+        } else {
+            let _ = write!(self.0, " {}={:?}", field.name(), value);
+        }
+    }
+}
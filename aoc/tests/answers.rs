@@ -0,0 +1,110 @@
+use aoc::answers::{canonical, input_hash, Answers, Check, KnownAnswer};
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+const ART: &str = "#####\n#...#\n#####";
+
+/// A directory of its own for each test, emptied first.
+fn scratch(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-answers-{}-{}", std::process::id(), name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn answers_survive_saving_and_loading() {
+    let path = scratch("round-trip").join("answers.toml");
+    let mut answers = Answers::default();
+    answers.insert(KnownAnswer::new(13, 2, "input", ART));
+    answers.insert(KnownAnswer::new(1, 1, "input", "7"));
+    answers.save(&path).unwrap();
+
+    let loaded = Answers::load(&path).unwrap();
+    assert_eq!(loaded.get(1, 1, &input_hash("input")), Some("7"));
+    assert_eq!(loaded.get(13, 2, &input_hash("input")), Some(ART));
+    assert_eq!(loaded.check(&KnownAnswer::new(13, 2, "input", ART)), Check::Ok);
+}
+
+#[test]
+fn a_missing_file_holds_no_answer() {
+    let path = scratch("missing").join("answers.toml");
+    let answers = Answers::load(&path).unwrap();
+    assert_eq!(answers.check(&KnownAnswer::new(1, 1, "input", "7")), Check::Missing);
+}
+
+#[test]
+fn a_malformed_file_is_an_error() {
+    let path = scratch("malformed").join("answers.toml");
+    fs::write(&path, "[[answer]]\nday = \"one\"\n").unwrap();
+    assert!(Answers::load(&path).is_err());
+}
+
+#[test]
+fn answers_are_checked_for_their_part_and_input_only() {
+    let mut answers = Answers::default();
+    answers.insert(KnownAnswer::new(1, 1, "input", "7"));
+    // Known answers for other parts and inputs are ignored.
+    answers.insert(KnownAnswer::new(1, 2, "input", "5"));
+    answers.insert(KnownAnswer::new(1, 1, "other input", "8"));
+
+    assert_eq!(answers.check(&KnownAnswer::new(1, 1, "input", "7")), Check::Ok);
+    assert_eq!(
+        answers.check(&KnownAnswer::new(1, 1, "input", "8")),
+        Check::Mismatch("7".to_string())
+    );
+    assert_eq!(answers.check(&KnownAnswer::new(2, 1, "input", "7")), Check::Missing);
+    assert_eq!(answers.check(&KnownAnswer::new(1, 1, "new input", "7")), Check::Missing);
+}
+
+#[test]
+fn art_is_compared_in_its_canonical_form() {
+    assert_eq!(canonical(" 42 \n"), "42");
+    assert_eq!(canonical("\n#####  \r\n#...#\r\n#####\n\n"), ART);
+    // Rows are not moved around, only the ends of the lines are.
+    assert_ne!(canonical("#####\n #...#\n#####"), ART);
+
+    let mut answers = Answers::default();
+    answers.insert(KnownAnswer {
+        answer: ART.replace('\n', "\r\n"),
+        ..KnownAnswer::new(13, 2, "", "")
+    });
+    assert_eq!(answers.check(&KnownAnswer::new(13, 2, "", ART)), Check::Ok);
+}
+
+#[test]
+fn verify_records_then_checks_the_answers() {
+    let dir = scratch("verify");
+    let (input, answers) = (dir.join("day1.txt"), dir.join("answers.toml"));
+    fs::write(&input, "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n").unwrap();
+    let verify = |extra: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["verify", "1", "--input"])
+            .arg(&input)
+            .arg("--answers")
+            .arg(&answers)
+            .args(extra)
+            .output()
+            .unwrap()
+    };
+
+    let output = verify(&[]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("0 ok, 0 mismatched, 2 missing"));
+
+    assert!(verify(&["--record"]).status.success());
+    let recorded = Answers::load(&answers).unwrap();
+    assert_eq!(recorded.get(1, 1, &input_hash(&fs::read_to_string(&input).unwrap())), Some("7"));
+
+    let output = verify(&[]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("2 ok, 0 mismatched, 0 missing"));
+
+    let wrong = fs::read_to_string(&answers).unwrap().replace("\"7\"", "\"8\"");
+    fs::write(&answers, wrong).unwrap();
+    let output = verify(&[]);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("day 1 part 1: mismatch: expected 8, got 7"), "{}", stdout);
+}
@@ -0,0 +1,29 @@
+use aoc::solvers::SOLVERS;
+use common::{Error, Solution};
+
+/// Small sizes keep every day fast, even the ones whose work grows quickly with their input.
+const SIZE: usize = 10;
+
+#[test]
+fn solvers_agree_with_generators() {
+    for seed in 0..3 {
+        for solver in SOLVERS {
+            let generated = generator::generate(solver.day, seed, SIZE).unwrap();
+            let answer = (solver.run)(&generated.input);
+            let context = format!("day {} part {} seed {}", solver.day, solver.part, seed);
+            match generated.answer(solver.part) {
+                Some(expected) => assert_eq!(answer.as_ref(), Ok(expected), "{}", context),
+                // Random octopuses may never all flash together, which is still an answer.
+                None => {
+                    assert!(!matches!(answer, Err(Error::Parse(_))), "{}: {:?}", context, answer)
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn amphipods_are_generated_in_the_burrow() {
+    let generated = generator::generate(23, 0, SIZE).unwrap();
+    assert!(day23::Day23::parse(&generated.input).is_ok());
+}
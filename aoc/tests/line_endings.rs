@@ -0,0 +1,27 @@
+use aoc::solvers::SOLVERS;
+use std::fs;
+use std::path::Path;
+
+/// The input the tests of a day keep, or a generated one for the days whose examples are inline.
+fn input(day: u8) -> String {
+    let tests = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../day{}/tests", day));
+    ["example.txt", "generated.txt"]
+        .iter()
+        .find_map(|name| fs::read_to_string(tests.join(name)).ok())
+        .unwrap_or_else(|| generator::generate(day, 0, 10).unwrap().input)
+}
+
+#[test]
+fn windows_files_give_the_same_answers() {
+    for solver in SOLVERS {
+        let input = input(solver.day);
+        let windows = format!("\u{feff}{}", input.replace('\n', "\r\n"));
+        assert_eq!(
+            (solver.run)(&windows),
+            (solver.run)(&input),
+            "day {} part {}",
+            solver.day,
+            solver.part
+        );
+    }
+}
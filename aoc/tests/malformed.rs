@@ -0,0 +1,92 @@
+//! Parsers must turn any input into a model or an error, never panic, and neither must the
+//! solvers given any model a parser accepts. The fuzz targets of `fuzz/` explore this at length;
+//! these mutations of generated inputs keep it checked.
+
+use aoc::solvers::{DAYS, SOLVERS};
+use common::cancel::Deadline;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+/// Characters that mean something to at least one of the parsers.
+const CHARACTERS: &[char] = &[
+    '0', '1', '5', '9', 'a', 'x', 'z', 'A', 'F', 'S', '#', '.', '>', 'v', ',', '-', '=', ':', ' ',
+    '\n', '[', ']', '(', '{', '<', '|', 'é',
+];
+
+fn boundary(rng: &mut ChaCha8Rng, input: &str) -> usize {
+    let boundaries = (0..=input.len()).filter(|&i| input.is_char_boundary(i)).collect::<Vec<_>>();
+    *boundaries.choose(rng).unwrap()
+}
+
+fn mutate(rng: &mut ChaCha8Rng, input: &str) -> String {
+    let mut input = input.to_string();
+    for _ in 0..rng.gen_range(1..=3) {
+        let i = boundary(rng, &input);
+        match rng.gen_range(0..5) {
+            0 => input.truncate(i),
+            1 => input.insert(i, *CHARACTERS.choose(rng).unwrap()),
+            2 => {
+                if let Some(c) = input[i..].chars().next() {
+                    input.replace_range(i..i + c.len_utf8(), "");
+                }
+            }
+            3 => {
+                if let Some(c) = input[i..].chars().next() {
+                    let replacement = CHARACTERS.choose(rng).unwrap().to_string();
+                    input.replace_range(i..i + c.len_utf8(), &replacement);
+                }
+            }
+            _ => {
+                let j = boundary(rng, &input);
+                let (from, to) = (i.min(j), i.max(j));
+                let copy = input[from..to].to_string();
+                input.insert_str(to, &copy);
+            }
+        }
+    }
+    input
+}
+
+/// Runs both parts of `day` on `input`, giving up on the ones that take long.
+fn solve(day: u8, input: &str) {
+    for solver in SOLVERS.iter().filter(|solver| solver.day == day) {
+        let deadline = Deadline::after(Duration::from_millis(50));
+        let solved = panic::catch_unwind(AssertUnwindSafe(|| deadline.run(|| (solver.run)(input))));
+        assert!(solved.is_ok(), "day {} part {} panicked on {:?}", day, solver.part, input);
+    }
+}
+
+#[test]
+fn mutated_inputs_do_not_panic() {
+    let rng = &mut ChaCha8Rng::seed_from_u64(0);
+    for day in DAYS {
+        for seed in 0..10 {
+            let input = generator::generate(day.day, seed, 5).unwrap().input;
+            for attempt in 0..200 {
+                let mutated = mutate(rng, &input);
+                let parsed = panic::catch_unwind(|| (day.parse)(&mutated));
+                assert!(parsed.is_ok(), "day {} panicked on {:?}", day.day, mutated);
+                // Solving takes far longer than parsing, so only some of the inputs are solved.
+                if parsed.unwrap().is_ok() && attempt % 10 == 0 {
+                    solve(day.day, &mutated);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn empty_inputs_do_not_panic() {
+    for day in DAYS {
+        for input in ["", "\n", "\n\n", " ", "é"] {
+            let parsed = panic::catch_unwind(|| (day.parse)(input));
+            assert!(parsed.is_ok(), "day {} panicked on {:?}", day.day, input);
+            if parsed.unwrap().is_ok() {
+                solve(day.day, input);
+            }
+        }
+    }
+}
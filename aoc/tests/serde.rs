@@ -0,0 +1,49 @@
+use common::Solution;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Checks that the model of a generated input comes back from JSON solving both parts the same.
+/// The JSON itself is not compared, as sets come out in any order.
+fn round_trip<S: Solution<Model: Serialize + DeserializeOwned>>(day: u8) {
+    let generated = generator::generate(day, 0, 10).unwrap();
+    let model = S::read(&generated.input).unwrap();
+    let back: S::Model = serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(S::part1(&back), S::part1(&model), "day {} part 1", day);
+    assert_eq!(S::part2(&back), S::part2(&model), "day {} part 2", day);
+}
+
+#[test]
+fn models_round_trip_through_json() {
+    round_trip::<day1::Day1>(1);
+    round_trip::<day2::Day2>(2);
+    round_trip::<day3::Day3>(3);
+    round_trip::<day4::Day4>(4);
+    round_trip::<day5::Day5>(5);
+    round_trip::<day6::Day6>(6);
+    round_trip::<day7::Day7>(7);
+    round_trip::<day8::Day8>(8);
+    round_trip::<day9::Day9>(9);
+    round_trip::<day10::Day10>(10);
+    round_trip::<day11::Day11>(11);
+    round_trip::<day12::Day12>(12);
+    round_trip::<day13::Day13>(13);
+    round_trip::<day14::Day14>(14);
+    round_trip::<day15::Day15>(15);
+    round_trip::<day16::Day16>(16);
+    round_trip::<day17::Day17>(17);
+    round_trip::<day18::Day18>(18);
+    round_trip::<day19::Day19>(19);
+    round_trip::<day20::Day20>(20);
+    round_trip::<day21::Day21>(21);
+    round_trip::<day22::Day22>(22);
+    round_trip::<day23::Day23>(23);
+    round_trip::<day24::Day24>(24);
+    round_trip::<day25::Day25>(25);
+}
+
+#[test]
+fn snailfish_numbers_are_nested_arrays() {
+    let model = day18::Day18::read("[[1,2],3]\n[4,[5,[6,7]]]\n").unwrap();
+    assert_eq!(serde_json::to_string(&model).unwrap(), "[[[1,2],3],[4,[5,[6,7]]]]",);
+    assert!(serde_json::from_str::<day18::Snailfish>("3").is_err());
+}
@@ -0,0 +1,36 @@
+use aoc::steering;
+use common::{Output, Solution};
+use day2::{follow, Day2};
+use std::path::Path;
+use std::process::Command;
+
+fn example() -> String {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../day2/tests/example.txt").display().to_string()
+}
+
+#[test]
+fn registered_models_are_found_by_name() {
+    let names = steering::models().map(|steering| steering.name).collect::<Vec<_>>();
+    assert_eq!(names, ["direct", "aim", "drag"]);
+    assert!(steering::find("sideways").is_none());
+
+    let actions = Day2::read(&std::fs::read_to_string(example()).unwrap()).unwrap();
+    let drag = steering::find("drag").unwrap();
+    // The aim of 5 drops to 3 after going forward 8, so it is 8 rather than 10 for the last 2.
+    let output = follow((drag.new)().as_mut(), &actions);
+    assert_eq!(output, Output::from(840).with("depth", 56).with("position", 15));
+}
+
+#[test]
+fn steer_picks_registered_models() {
+    let steer = |model: &str| {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["steer", model, "--input", &example()])
+            .output()
+            .unwrap()
+    };
+    let output = steer("drag");
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "840 (depth 56, position 15)\n");
+    assert!(!steer("sideways").status.success());
+}
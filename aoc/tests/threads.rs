@@ -0,0 +1,24 @@
+use aoc::solvers::SOLVERS;
+use std::fs;
+use std::path::Path;
+
+/// The days whose solvers run in parallel, with the input their tests keep.
+const PARALLEL: [(u8, &str); 3] =
+    [(18, "example.txt"), (19, "generated.txt"), (20, "generated.txt")];
+
+#[test]
+fn thread_count_does_not_change_answers() {
+    for (day, name) in PARALLEL {
+        let path =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../day{}/tests/{}", day, name));
+        let input = fs::read_to_string(path).unwrap();
+        for solver in SOLVERS.iter().filter(|solver| solver.day == day) {
+            let answers = [1, 2, 8].map(|threads| {
+                let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+                pool.install(|| (solver.run)(&input))
+            });
+            assert_eq!(answers[0], answers[1], "day {} part {}", day, solver.part);
+            assert_eq!(answers[0], answers[2], "day {} part {}", day, solver.part);
+        }
+    }
+}
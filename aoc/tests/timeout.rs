@@ -0,0 +1,86 @@
+use aoc::solvers::{Solver, SOLVERS};
+use common::cancel::{Deadline, Token};
+use common::Error;
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Small caves all linked through big ones, with far more paths than could ever be counted.
+fn maze() -> String {
+    let mut lines = vec![];
+    for small in "abcdefghijkl".chars() {
+        lines.push(format!("start-{}", small));
+        lines.push(format!("{}-end", small));
+        for big in "ABCD".chars() {
+            lines.push(format!("{}-{}", small, big));
+        }
+    }
+    lines.join("\n")
+}
+
+fn solver(day: u8) -> &'static Solver {
+    SOLVERS.iter().find(|solver| solver.day == day && solver.part == 2).unwrap()
+}
+
+#[test]
+fn solvers_give_up_past_the_deadline() {
+    let started = Instant::now();
+    let deadline = Deadline::after(Duration::from_millis(100));
+    assert_eq!(deadline.run(|| (solver(12).run)(&maze())), Err(Error::TimedOut));
+    assert!(started.elapsed() < Duration::from_secs(5));
+
+    // Searches check before each batch of states, even one that would end the search.
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../day24/tests/generated.txt");
+    let program = fs::read_to_string(path).unwrap();
+    let deadline = Deadline::after(Duration::ZERO);
+    thread::sleep(Duration::from_millis(10));
+    assert_eq!(deadline.run(|| (solver(24).run)(&program)), Err(Error::TimedOut));
+
+    // Big caves next to each other would have the count go on forever, or overflow the stack.
+    let deadline = Deadline::after(Duration::from_secs(2));
+    let answer = deadline.run(|| (solver(12).run)("start-A\nA-B\nB-end\n"));
+    assert!(matches!(answer, Err(Error::Parse(_))), "{:?}", answer);
+
+    // Outside of the run, the deadline is of no concern.
+    assert_eq!((solver(12).run)("start-A\nA-end\n"), Ok(1.into()));
+}
+
+#[test]
+fn every_solver_checks_for_cancellation() {
+    let token = Token::new();
+    token.cancel();
+    for solver in SOLVERS {
+        let input = generator::generate(solver.day, 0, 10).unwrap().input;
+        assert_eq!(
+            token.install(|| (solver.run)(&input)),
+            Err(Error::TimedOut),
+            "day {} part {}",
+            solver.day,
+            solver.part
+        );
+    }
+}
+
+#[test]
+fn runs_are_cancelled_on_their_own() {
+    let cancelled = thread::spawn(|| {
+        let deadline = Deadline::after(Duration::from_millis(50));
+        deadline.run(|| (solver(12).run)(&maze()))
+    });
+    // Runs on other threads, and those started later, go on undisturbed.
+    let input = generator::generate(19, 0, 10).unwrap().input;
+    let answer = Deadline::after(Duration::from_secs(600)).run(|| (solver(19).run)(&input));
+    assert!(answer.is_ok(), "{:?}", answer);
+    assert_eq!(cancelled.join().unwrap(), Err(Error::TimedOut));
+    assert_eq!((solver(12).run)("start-A\nA-end\n"), Ok(1.into()));
+}
+
+#[test]
+fn parallel_solvers_take_the_token_along() {
+    let input = generator::generate(18, 0, 10).unwrap().input;
+    let token = Token::new();
+    token.cancel();
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+    assert_eq!(pool.install(|| token.install(|| (solver(18).run)(&input))), Err(Error::TimedOut));
+}
@@ -0,0 +1,28 @@
+use aoc::solvers::SOLVERS;
+use aoc::trace::{self, Guard, Trace};
+use std::fs;
+use std::path::Path;
+use tracing::Level;
+
+#[test]
+fn summary_times_phases_and_counts_lookups() {
+    let guard = trace::start(Trace::Summary, Level::DEBUG);
+    for (day, name) in [(19, "generated.txt"), (24, "generated.txt")] {
+        let path =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../day{}/tests/{}", day, name));
+        let input = fs::read_to_string(path).unwrap();
+        let solver = SOLVERS.iter().find(|solver| solver.day == day && solver.part == 1).unwrap();
+        (solver.run)(&input).unwrap();
+    }
+
+    let Guard::Summary(summary) = &guard else { unreachable!() };
+    let summary = summary.lock().unwrap().to_string();
+    for expected in [
+        "parse{solver=Day19}",
+        "solve{solver=Day19 part=1} > align",
+        "solve{solver=Day24 part=1} > search: search over found=true",
+        "lru lookups cache=inputs_resolves hits=",
+    ] {
+        assert!(summary.contains(expected), "{} in\n{}", expected, summary);
+    }
+}
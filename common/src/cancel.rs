@@ -0,0 +1,135 @@
+//! Cooperative cancellation: solvers call [`check`] in their main loops, and give up with
+//! [`Error::TimedOut`] once their run is cancelled, past its [`Deadline`] or through its [`Token`].
+//!
+//! Each run has a token of its own, installed on the thread that runs it, so that runs going on
+//! at the same time never cancel one another. Solvers that hand work to other threads take the
+//! [`current`] token along and install it there.
+
+use crate::{Error, Result};
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Cancels one run, from whichever thread holds a clone of it.
+#[derive(Clone, Debug, Default)]
+pub struct Token(Arc<AtomicBool>);
+
+impl Token {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes the solvers of the run give up at their next check.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            Err(Error::TimedOut)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Runs `f` on this thread with this token as the one [`check`] looks at, then puts the
+    /// previous one back.
+    pub fn install<T>(&self, f: impl FnOnce() -> T) -> T {
+        struct Restore(Option<Token>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+            }
+        }
+
+        let _restore = Restore(CURRENT.with(|current| current.replace(Some(self.clone()))));
+        f()
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// The token installed on this thread, or one that is never cancelled.
+pub fn current() -> Token {
+    CURRENT.with(|current| current.borrow().clone()).unwrap_or_default()
+}
+
+/// Cheap enough for inner loops: it only reads the flag of the token installed on this thread.
+pub fn check() -> Result<()> {
+    CURRENT.with(|current| current.borrow().as_ref().map_or(Ok(()), Token::check))
+}
+
+/// Ends `items` early once the run is cancelled, for the solvers that make a single pass over
+/// their input. They [`check`] afterwards, to tell a cancelled pass from a finished one.
+pub fn checked<I: IntoIterator>(items: I) -> Checked<I::IntoIter> {
+    Checked { items: items.into_iter(), token: current() }
+}
+
+/// The iterator of [`checked`].
+pub struct Checked<I> {
+    items: I,
+    token: Token,
+}
+
+impl<I: Iterator> Iterator for Checked<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.token.is_cancelled() {
+            None
+        } else {
+            self.items.next()
+        }
+    }
+}
+
+/// Cancels its token once its time is up, unless dropped first.
+pub struct Deadline {
+    token: Token,
+    stop: Option<Sender<()>>,
+    timer: Option<JoinHandle<()>>,
+}
+
+impl Deadline {
+    pub fn after(timeout: Duration) -> Self {
+        let token = Token::new();
+        let (stop, stopped) = mpsc::channel::<()>();
+        let timer = thread::spawn({
+            let token = token.clone();
+            move || {
+                if let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(timeout) {
+                    token.cancel();
+                }
+            }
+        });
+        Self { token, stop: Some(stop), timer: Some(timer) }
+    }
+
+    pub fn token(&self) -> &Token {
+        &self.token
+    }
+
+    /// Runs `f` on this thread, cancelled once the deadline is past.
+    pub fn run<T>(&self, f: impl FnOnce() -> T) -> T {
+        self.token.install(f)
+    }
+}
+
+impl Drop for Deadline {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(timer) = self.timer.take() {
+            let _ = timer.join();
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{ParseError, Source};
+use std::borrow::Cow;
+
+/// `input` with Unix line endings and without a byte order mark, as saved by some Windows
+/// editors. Borrowed when there is nothing to change.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    if input.contains('\r') {
+        Cow::Owned(input.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// The groups of lines of `input` separated by blank lines, without their last line break.
+pub fn sections(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = input;
+    std::iter::from_fn(move || {
+        let start = rest.len() - rest.trim_start_matches(|c: char| c.is_whitespace()).len();
+        let start = rest[..start].rfind('\n').map_or(0, |i| i + 1);
+        rest = &rest[start..];
+        if rest.trim().is_empty() {
+            return None;
+        }
+
+        let mut end = 0;
+        for line in rest.split_inclusive('\n') {
+            if line.trim().is_empty() {
+                break;
+            }
+            end += line.len();
+        }
+        let (section, after) = rest.split_at(end);
+        rest = after;
+        Some(section.trim_end_matches(['\n', '\r']))
+    })
+}
+
+/// The signed integers written in `text`, whatever separates them.
+pub fn integers(text: &str) -> impl Iterator<Item = &str> {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        let is_start = |i: usize| {
+            bytes[i].is_ascii_digit()
+                || (bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit))
+        };
+        while i < bytes.len() && !is_start(i) {
+            i += 1;
+        }
+        if i == bytes.len() {
+            return None;
+        }
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        Some(&text[start..i])
+    })
+}
+
+/// The cells of a rectangular map of characters borrowed from the input of `source`, row by
+/// row, and its width. `cell` reads each character; the ones it rejects are not what was
+/// `expected`.
+pub fn char_grid<T>(
+    source: Source<'_>,
+    section: &str,
+    expected: &str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> Result<(usize, Vec<T>), ParseError> {
+    let mut cells = vec![];
+    let mut width = None;
+
+    for line in section.lines() {
+        let mut line_width = 0;
+        for (i, c) in line.char_indices() {
+            if width.is_some_and(|width| line_width == width) {
+                return Err(source.error(&line[i..], "end of line"));
+            }
+            cells.push(cell(c).ok_or_else(|| source.unexpected(&line[i..], expected))?);
+            line_width += 1;
+        }
+        match width {
+            None if line_width == 0 => return Err(source.missing(line, expected)),
+            None => width = Some(line_width),
+            Some(width) if width != line_width => {
+                return Err(source.missing(line, format!("a line of {} cells", width)))
+            }
+            Some(_) => {}
+        }
+    }
+
+    width.map(|width| (width, cells)).ok_or_else(|| source.missing(section, expected))
+}
@@ -0,0 +1,138 @@
+pub mod cancel;
+pub mod input;
+#[cfg(feature = "serde")]
+pub mod pairs;
+pub mod parse;
+pub mod trace;
+
+pub use parse::{ParseError, Source};
+use std::fmt::{self, Display, Formatter};
+
+/// What a solver reports for one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(i64::try_from(n).expect("answer does not fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i8, i16, i32, i64, u8, u16, u32, u64, usize, i128, u128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// An answer along with intermediate results worth reporting, such as where the crabs align.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Output {
+    pub answer: Answer,
+    pub extra: Vec<(&'static str, Answer)>,
+}
+
+impl Output {
+    pub fn with(mut self, name: &'static str, value: impl Into<Answer>) -> Self {
+        self.extra.push((name, value.into()));
+        self
+    }
+}
+
+impl<T: Into<Answer>> From<T> for Output {
+    fn from(answer: T) -> Self {
+        Self { answer: answer.into(), extra: vec![] }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input does not match the puzzle format.
+    Parse(ParseError),
+    /// The input is well-formed but has no answer.
+    NoSolution(String),
+    /// A long search could not be picked up from its checkpoint.
+    Checkpoint(String),
+    /// The solver was cancelled before it found the answer, see [`cancel`].
+    TimedOut,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(s) => write!(f, "parse error: {}", s),
+            Error::NoSolution(s) => write!(f, "no solution: {}", s),
+            Error::Checkpoint(s) => write!(f, "checkpoint error: {}", s),
+            Error::TimedOut => write!(f, "timed out"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// A day's puzzle: both parts are computed from the same parsed model.
+pub trait Solution {
+    type Model;
+
+    fn parse(input: &str) -> Result<Self::Model>;
+    fn part1(model: &Self::Model) -> Result<Answer>;
+    fn part2(model: &Self::Model) -> Result<Answer>;
+
+    /// Part 1 with its intermediate results; only the answer unless a day has more to say.
+    fn part1_output(model: &Self::Model) -> Result<Output> {
+        Self::part1(model).map(Output::from)
+    }
+
+    fn part2_output(model: &Self::Model) -> Result<Output> {
+        Self::part2(model).map(Output::from)
+    }
+
+    /// Parses an input however it was saved, with CRLF line endings or a byte order mark.
+    fn read(input: &str) -> Result<Self::Model> {
+        let _span = trace::parse_span::<Self>().entered();
+        Self::parse(&input::normalize(input))
+    }
+
+    fn solve_part1(input: &str) -> Result<Answer> {
+        let model = Self::read(input)?;
+        trace::solve_span::<Self>(1).in_scope(|| Self::part1(&model))
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer> {
+        let model = Self::read(input)?;
+        trace::solve_span::<Self>(2).in_scope(|| Self::part2(&model))
+    }
+}
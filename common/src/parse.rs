@@ -0,0 +1,172 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static STRICT: AtomicBool = AtomicBool::new(false);
+
+/// In strict mode every record must parse; otherwise malformed records are skipped.
+pub fn set_strict(strict: bool) {
+    STRICT.store(strict, Ordering::Relaxed);
+}
+
+pub fn is_strict() -> bool {
+    STRICT.load(Ordering::Relaxed)
+}
+
+/// Where the input stops matching the puzzle format, with 1-based line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        Self { line, column, expected: expected.into(), found: found.into() }
+    }
+
+    /// The message followed by the offending line of `input`, the error underlined.
+    pub fn diagnostic(&self, input: &str) -> String {
+        let mut output = self.to_string();
+        if let Some(line) = input.lines().nth(self.line.wrapping_sub(1)) {
+            let number = self.line.to_string();
+            let padding = " ".repeat(number.len());
+            let underline = "^".repeat(self.found.chars().count().max(1));
+            output.push_str(&format!("\n{} | {}", number, line));
+            output.push_str(&format!(
+                "\n{} | {}{}",
+                padding,
+                " ".repeat(self.column.saturating_sub(1)),
+                underline
+            ));
+        }
+        output
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            write!(f, "nothing")
+        } else {
+            write!(f, "{:?}", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A puzzle input, used to locate errors from slices borrowed out of it.
+#[derive(Clone, Copy, Debug)]
+pub struct Source<'a> {
+    input: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self { input }
+    }
+
+    fn position(&self, token: &str) -> (usize, usize) {
+        let start = self.input.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize).saturating_sub(start).min(self.input.len());
+        let before = &self.input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+    }
+
+    /// `token`, a slice of the input, is not what was `expected`.
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        let (line, column) = self.position(token);
+        ParseError::new(line, column, expected, token)
+    }
+
+    /// The first character of `rest`, a slice of the input, is not what was `expected`.
+    pub fn unexpected(&self, rest: &str, expected: impl Into<String>) -> ParseError {
+        let found = rest.chars().next().map_or(&rest[..0], |c| &rest[..c.len_utf8()]);
+        self.error(found, expected)
+    }
+
+    /// The input stopped right after `token` while more was `expected`.
+    pub fn missing(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        self.error(&token[token.len()..], expected)
+    }
+
+    /// The whole input ended while more was `expected`.
+    pub fn end(&self, expected: impl Into<String>) -> ParseError {
+        self.missing(self.input, expected)
+    }
+
+    /// Moves `error`, found while parsing `token` on its own, to where `token` is in the input.
+    pub fn locate(&self, token: &str, error: ParseError) -> ParseError {
+        let (line, column) = self.position(token);
+        let column =
+            if error.line <= 1 { column + error.column.saturating_sub(1) } else { error.column };
+        ParseError { line: line + error.line.saturating_sub(1), column, ..error }
+    }
+
+    pub fn number<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, "a number"))
+    }
+
+    /// The `N` signed integers written in `token`, whatever separates them.
+    pub fn integers<T: FromStr, const N: usize>(
+        &self,
+        token: &'a str,
+    ) -> Result<[T; N], ParseError> {
+        let mut numbers = crate::input::integers(token);
+        let parsed =
+            numbers.by_ref().take(N).map(|n| self.number(n)).collect::<Result<Vec<_>, _>>()?;
+        if let Some(extra) = numbers.next() {
+            return Err(self.error(extra, format!("only {} numbers", N)));
+        }
+        parsed.try_into().map_err(|_| self.missing(token, format!("{} numbers", N)))
+    }
+
+    pub fn split_once(
+        &self,
+        token: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        token.split_once(delimiter).ok_or_else(|| self.missing(token, format!("{:?}", delimiter)))
+    }
+
+    /// `token` with `prefix` removed, which must be there.
+    pub fn strip_prefix(&self, token: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        token.strip_prefix(prefix).ok_or_else(|| {
+            let end =
+                token.char_indices().nth(prefix.chars().count()).map_or(token.len(), |(i, _)| i);
+            self.error(&token[..end], format!("{:?}", prefix))
+        })
+    }
+
+    /// Parses each record, skipping the malformed ones unless in strict mode.
+    pub fn records<T>(
+        &self,
+        records: impl IntoIterator<Item = &'a str>,
+        mut parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let strict = is_strict();
+        let mut parsed = vec![];
+        for record in records {
+            match parse(record) {
+                Ok(value) => parsed.push(value),
+                Err(e) if strict => return Err(e),
+                Err(_) => {}
+            }
+        }
+        Ok(parsed)
+    }
+}
@@ -0,0 +1,17 @@
+use tracing::{info_span, Span};
+
+/// The name of a solver in traces, such as `Day19`.
+fn solver<S: ?Sized>() -> &'static str {
+    let name = std::any::type_name::<S>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// The span that parsing the input of `S` runs in.
+pub fn parse_span<S: ?Sized>() -> Span {
+    info_span!("parse", solver = solver::<S>())
+}
+
+/// The span that solving a part of `S` runs in, once the input is parsed.
+pub fn solve_span<S: ?Sized>(part: u8) -> Span {
+    info_span!("solve", solver = solver::<S>(), part)
+}
@@ -0,0 +1,34 @@
+use common::input::{integers, normalize, sections};
+use common::{ParseError, Source};
+
+#[test]
+fn normalizes_windows_files() {
+    assert_eq!(normalize("\u{feff}1,2\r\n\r\n3\r\n"), "1,2\n\n3\n");
+    assert!(matches!(normalize("1,2\n"), std::borrow::Cow::Borrowed("1,2\n")));
+}
+
+#[test]
+fn splits_sections_at_blank_lines() {
+    let input = "\n7,4,9\n\n 22 13\n  8  2\n  \n\n21 9\n";
+    assert_eq!(sections(input).collect::<Vec<_>>(), ["7,4,9", " 22 13\n  8  2", "21 9"]);
+    assert_eq!(sections("\n \n").count(), 0);
+}
+
+#[test]
+fn extracts_signed_integers() {
+    let text = "target area: x=20..30, y=-10..-5 -> 7-3";
+    assert_eq!(integers(text).collect::<Vec<_>>(), ["20", "30", "-10", "-5", "7", "-3"]);
+}
+
+#[test]
+fn expects_a_number_of_integers() {
+    let input = "1,2\n3,4,5\n6\n";
+    let source = Source::new(input);
+    let lines = input.lines().collect::<Vec<_>>();
+    assert_eq!(source.integers::<i32, 2>(lines[0]), Ok([1, 2]));
+    assert_eq!(
+        source.integers::<i32, 2>(lines[1]),
+        Err(ParseError::new(2, 5, "only 2 numbers", "5"))
+    );
+    assert_eq!(source.integers::<i32, 2>(lines[2]), Err(ParseError::new(3, 2, "2 numbers", "")));
+}
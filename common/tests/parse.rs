@@ -0,0 +1,20 @@
+use common::{ParseError, Source};
+
+const INPUT: &str = "forward 5\ndown x\n";
+
+#[test]
+fn locates_tokens() {
+    let source = Source::new(INPUT);
+    let x = &INPUT[15..16];
+    assert_eq!(source.error(x, "a number"), ParseError::new(2, 6, "a number", "x"));
+    assert_eq!(source.end("an action"), ParseError::new(3, 1, "an action", ""));
+}
+
+#[test]
+fn diagnostic_underlines_the_token() {
+    let error = ParseError::new(2, 6, "a number", "x");
+    assert_eq!(
+        error.diagnostic(INPUT),
+        "line 2, column 6: expected a number, found \"x\"\n2 | down x\n  |      ^"
+    );
+}
@@ -1,21 +1,8 @@
+use common::Solution;
+use day1::Day1;
 use std::io;
-use std::io::BufRead;
 
-fn main() {
-    let stdin = io::stdin();
-    let measurements = stdin.lock().lines().flatten().map(|x| x.parse::<i32>().unwrap());
-
-    let increases = measurements
-        .scan(None, |previous, current| {
-            let is_increase = match previous {
-                None => false,
-                Some(value) => current > *value,
-            };
-            *previous = Some(current);
-            Some(is_increase)
-        })
-        .filter(|is_increase| *is_increase)
-        .count();
-
-    println!("{}", increases);
+fn main() -> common::Result<()> {
+    println!("{}", Day1::solve_part1(&io::read_to_string(io::stdin()).unwrap())?);
+    Ok(())
 }
@@ -0,0 +1,120 @@
+pub mod sonar;
+
+use common::{Answer, Result, Solution, Source};
+use std::collections::VecDeque;
+
+pub struct Day1;
+
+/// How many sums of `window` consecutive measurements are larger than the sum just before.
+///
+/// Two neighbouring windows share all but their first and last measurements, so a sum increases
+/// exactly when the measurement entering the window is larger than the one leaving it. Only the
+/// last `window` measurements are kept, however long `measurements` is.
+///
+/// # Panics
+///
+/// If `window` is 0.
+pub fn count_increases<T: PartialOrd>(
+    measurements: impl IntoIterator<Item = T>,
+    window: usize,
+) -> usize {
+    assert!(window > 0, "windows hold at least one measurement");
+    measurements
+        .into_iter()
+        .scan(VecDeque::with_capacity(window), |previous: &mut VecDeque<T>, current| {
+            let leaving = if previous.len() == window { previous.pop_front() } else { None };
+            let is_increase = leaving.is_some_and(|leaving| current > leaving);
+            previous.push_back(current);
+            Some(is_increase)
+        })
+        .filter(|is_increase| *is_increase)
+        .count()
+}
+
+/// How much a window sum must rise to count as an increase, so that the jitter of noisy readings
+/// does not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tolerance {
+    /// The least rise over the previous sum that counts.
+    pub min_delta: i64,
+    /// Once an increase counted, the next one only counts after the sum has fallen by at least this
+    /// much from its highest since. A hysteresis of 0 is the same as none.
+    pub hysteresis: Option<i64>,
+}
+
+impl Default for Tolerance {
+    /// Every rise counts, as in the puzzle.
+    fn default() -> Self {
+        Self { min_delta: 1, hysteresis: None }
+    }
+}
+
+/// Like [`count_increases`], counting only the increases that `tolerance` lets through. Keeps
+/// the sum of the current window along with its measurements.
+///
+/// # Panics
+///
+/// If `window` is 0.
+pub fn count_increases_with(
+    measurements: impl IntoIterator<Item = i32>,
+    window: usize,
+    tolerance: Tolerance,
+) -> usize {
+    assert!(window > 0, "windows hold at least one measurement");
+    let mut current = VecDeque::with_capacity(window + 1);
+    let mut sum = 0;
+    let mut previous = None;
+    // The highest sum since the last increase, while waiting for the fall that allows the next.
+    let mut peak: Option<i64> = None;
+    let mut count = 0;
+    for measurement in measurements {
+        current.push_back(measurement);
+        sum += i64::from(measurement);
+        if current.len() > window {
+            sum -= i64::from(current.pop_front().expect("the window is not empty"));
+        } else if current.len() < window {
+            continue;
+        }
+
+        if let Some(previous) = previous {
+            let ready = match (peak, tolerance.hysteresis) {
+                (Some(highest), Some(hysteresis)) if sum <= highest - hysteresis => true,
+                (Some(highest), _) => {
+                    peak = Some(highest.max(sum));
+                    false
+                }
+                (None, _) => true,
+            };
+            if ready {
+                peak = None;
+                if sum - previous >= tolerance.min_delta {
+                    count += 1;
+                    peak = tolerance.hysteresis.filter(|&hysteresis| hysteresis > 0).map(|_| sum);
+                }
+            }
+        }
+        previous = Some(sum);
+    }
+    count
+}
+
+impl Solution for Day1 {
+    type Model = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let source = Source::new(input);
+        Ok(source.records(input.lines(), |line| source.number(line))?)
+    }
+
+    fn part1(measurements: &Self::Model) -> Result<Answer> {
+        let increases = count_increases(common::cancel::checked(measurements), 1);
+        common::cancel::check()?;
+        Ok(increases.into())
+    }
+
+    fn part2(measurements: &Self::Model) -> Result<Answer> {
+        let increases = count_increases(common::cancel::checked(measurements), 3);
+        common::cancel::check()?;
+        Ok(increases.into())
+    }
+}
@@ -0,0 +1,266 @@
+//! What a sonar sweep says beyond how often the depth increases: runs, drops and readings that
+//! stand out from the ones before them.
+
+use crate::{count_increases_with, Tolerance};
+use std::collections::VecDeque;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Write};
+
+/// Makes the median absolute deviation of normally distributed readings an estimate of their
+/// standard deviation.
+const MAD_SCALE: f64 = 1.4826;
+/// The least deviation outliers are judged against: readings are whole depths, so a flat stretch
+/// of them does not make every change an outlier.
+const MIN_DEVIATION: f64 = 1.0;
+
+/// How outliers are told apart: a reading is one when it is further from the median of the
+/// `history` readings before it than `sensitivity` times their scaled median absolute deviation,
+/// taken as at least 1. The first `history` readings are never outliers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Detector {
+    /// How many of the previous readings the median and deviation are taken over.
+    pub history: usize,
+    pub sensitivity: f64,
+}
+
+impl Default for Detector {
+    fn default() -> Self {
+        Self { history: 11, sensitivity: 3.0 }
+    }
+}
+
+/// A reading and what the sweep makes of it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Annotation {
+    pub index: usize,
+    pub depth: i32,
+    /// The change from the previous reading.
+    pub change: Option<i64>,
+    /// How many readings the strictly increasing run ending with this one holds.
+    pub run: usize,
+    /// The median of the readings in the history, once it is full.
+    pub median: Option<f64>,
+    /// The median absolute deviation of the readings in the history from their median.
+    pub deviation: Option<f64>,
+    pub outlier: bool,
+}
+
+fn median(values: &mut [f64]) -> f64 {
+    values.sort_by(f64::total_cmp);
+    let middle = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[middle - 1] + values[middle]) / 2.0
+    } else {
+        values[middle]
+    }
+}
+
+/// What annotating a reading needs to know of the ones before it.
+struct Sweep {
+    detector: Detector,
+    history: VecDeque<i32>,
+    previous: Option<i32>,
+    run: usize,
+}
+
+impl Sweep {
+    fn annotate(&mut self, index: usize, depth: i32) -> Annotation {
+        let change = self.previous.map(|previous| i64::from(depth) - i64::from(previous));
+        self.run = if change.is_some_and(|change| change > 0) { self.run + 1 } else { 1 };
+
+        let (median, deviation) = if self.history.is_empty()
+            || self.history.len() < self.detector.history
+        {
+            (None, None)
+        } else {
+            let mut depths = self.history.iter().map(|&depth| f64::from(depth)).collect::<Vec<_>>();
+            let median = median(&mut depths);
+            let mut deviations =
+                depths.iter().map(|depth| (depth - median).abs()).collect::<Vec<_>>();
+            (Some(median), Some(self::median(&mut deviations)))
+        };
+        let outlier = median.zip(deviation).is_some_and(|(median, deviation)| {
+            let deviation = deviation.max(MIN_DEVIATION);
+            (f64::from(depth) - median).abs() > self.detector.sensitivity * MAD_SCALE * deviation
+        });
+
+        if self.history.len() == self.detector.history {
+            self.history.pop_front();
+        }
+        if self.detector.history > 0 {
+            self.history.push_back(depth);
+        }
+        self.previous = Some(depth);
+
+        Annotation { index, depth, change, run: self.run, median, deviation, outlier }
+    }
+}
+
+/// Annotates each reading as it comes, keeping only `detector.history` readings.
+pub fn annotate(
+    readings: impl IntoIterator<Item = i32>,
+    detector: Detector,
+) -> impl Iterator<Item = Annotation> {
+    let sweep = Sweep {
+        detector,
+        history: VecDeque::with_capacity(detector.history),
+        previous: None,
+        run: 0,
+    };
+    readings
+        .into_iter()
+        .enumerate()
+        .scan(sweep, |sweep, (index, depth)| Some(sweep.annotate(index, depth)))
+}
+
+/// Writes the annotations as CSV, leaving out what a reading has no value for.
+pub fn write_csv(
+    annotations: impl IntoIterator<Item = Annotation>,
+    mut writer: impl Write,
+) -> io::Result<()> {
+    fn optional<T: Display>(value: Option<T>) -> String {
+        value.map_or_else(String::new, |value| value.to_string())
+    }
+
+    writeln!(writer, "index,depth,change,run,median,deviation,outlier")?;
+    for annotation in annotations {
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{}",
+            annotation.index,
+            annotation.depth,
+            optional(annotation.change),
+            annotation.run,
+            optional(annotation.median),
+            optional(annotation.deviation),
+            annotation.outlier
+        )?;
+    }
+    Ok(())
+}
+
+/// A strictly increasing run of readings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub start: usize,
+    pub length: usize,
+}
+
+/// A decrease from one reading to the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decrease {
+    /// The reading after the decrease.
+    pub index: usize,
+    pub by: i64,
+}
+
+/// How many sums of `window` readings rose enough over the one before, out of how many.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowIncreases {
+    pub window: usize,
+    pub increases: usize,
+    pub comparisons: usize,
+}
+
+impl WindowIncreases {
+    pub fn percentage(&self) -> Option<f64> {
+        (self.comparisons > 0).then(|| self.increases as f64 * 100.0 / self.comparisons as f64)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub readings: usize,
+    /// The first of the longest runs.
+    pub longest_run: Option<Run>,
+    /// The first of the largest decreases.
+    pub largest_drop: Option<Decrease>,
+    /// What counts as an increase.
+    pub tolerance: Tolerance,
+    pub increases: Vec<WindowIncreases>,
+    /// The indices of the outliers.
+    pub outliers: Vec<usize>,
+}
+
+impl Report {
+    /// Sums up the sweep, counting the increases within `tolerance` over each of `windows`.
+    ///
+    /// # Panics
+    ///
+    /// If a window is 0.
+    pub fn new(
+        readings: &[i32],
+        windows: &[usize],
+        tolerance: Tolerance,
+        detector: Detector,
+    ) -> Self {
+        let mut report = Self {
+            readings: readings.len(),
+            longest_run: None,
+            largest_drop: None,
+            tolerance,
+            increases: vec![],
+            outliers: vec![],
+        };
+        for annotation in annotate(readings.iter().copied(), detector) {
+            if report.longest_run.is_none_or(|run| annotation.run > run.length) {
+                let start = annotation.index + 1 - annotation.run;
+                report.longest_run = Some(Run { start, length: annotation.run });
+            }
+            if let Some(change) = annotation.change.filter(|&change| change < 0) {
+                if report.largest_drop.is_none_or(|drop| -change > drop.by) {
+                    report.largest_drop = Some(Decrease { index: annotation.index, by: -change });
+                }
+            }
+            if annotation.outlier {
+                report.outliers.push(annotation.index);
+            }
+        }
+        report.increases = windows
+            .iter()
+            .map(|&window| WindowIncreases {
+                window,
+                increases: count_increases_with(readings.iter().copied(), window, tolerance),
+                comparisons: readings.len().saturating_sub(window),
+            })
+            .collect();
+        report
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "readings: {}", self.readings)?;
+        if let Some(run) = self.longest_run {
+            writeln!(f, "longest increasing run: {} readings from #{}", run.length, run.start)?;
+        }
+        match self.largest_drop {
+            Some(drop) => writeln!(f, "largest drop: {} at #{}", drop.by, drop.index)?,
+            None => writeln!(f, "largest drop: none")?,
+        }
+        if self.tolerance != Tolerance::default() {
+            write!(f, "increases count rises of at least {}", self.tolerance.min_delta)?;
+            match self.tolerance.hysteresis {
+                Some(hysteresis) => writeln!(f, ", each after a fall of at least {}", hysteresis)?,
+                None => writeln!(f)?,
+            }
+        }
+        for increases in &self.increases {
+            write!(
+                f,
+                "increases over windows of {}: {} of {}",
+                increases.window, increases.increases, increases.comparisons
+            )?;
+            match increases.percentage() {
+                Some(percentage) => writeln!(f, " ({:.1}%)", percentage)?,
+                None => writeln!(f)?,
+            }
+        }
+        if self.outliers.is_empty() {
+            writeln!(f, "outliers: none")
+        } else {
+            let outliers = self.outliers.iter().map(|index| format!("#{}", index));
+            writeln!(f, "outliers: {}", outliers.collect::<Vec<_>>().join(" "))
+        }
+    }
+}
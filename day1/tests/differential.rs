@@ -0,0 +1,25 @@
+use day1::{count_increases, count_increases_with, Tolerance};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn streaming_agrees_with_summing_windows(
+        measurements in prop::collection::vec(0..200i32, 0..50),
+        window in 1..6usize,
+    ) {
+        let sums = measurements.windows(window).map(|window| window.iter().sum::<i32>());
+        let expected = sums.collect::<Vec<_>>().windows(2).filter(|pair| pair[1] > pair[0]).count();
+        prop_assert_eq!(count_increases(&measurements, window), expected);
+    }
+
+    #[test]
+    fn every_rise_counts_by_default(
+        measurements in prop::collection::vec(0..200i32, 0..50),
+        window in 1..6usize,
+    ) {
+        prop_assert_eq!(
+            count_increases_with(measurements.iter().copied(), window, Tolerance::default()),
+            count_increases(&measurements, window),
+        );
+    }
+}
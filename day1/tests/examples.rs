@@ -0,0 +1,14 @@
+use common::Solution;
+use day1::Day1;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day1::solve_part1(EXAMPLE), Ok(7.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day1::solve_part2(EXAMPLE), Ok(5.into()));
+}
@@ -0,0 +1,63 @@
+use common::Solution;
+use day1::sonar::{self, Decrease, Detector, Report, Run, WindowIncreases};
+use day1::{Day1, Tolerance};
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn report_of_the_example() {
+    let readings = Day1::read(EXAMPLE).unwrap();
+    let report = Report::new(&readings, &[1, 3], Tolerance::default(), Detector::default());
+    assert_eq!(report.longest_run, Some(Run { start: 0, length: 4 }));
+    assert_eq!(report.largest_drop, Some(Decrease { index: 4, by: 10 }));
+    assert_eq!(
+        report.increases,
+        [
+            WindowIncreases { window: 1, increases: 7, comparisons: 9 },
+            WindowIncreases { window: 3, increases: 5, comparisons: 7 },
+        ]
+    );
+    assert!(report.outliers.is_empty());
+}
+
+#[test]
+fn spikes_stand_out_from_the_readings_before() {
+    let readings = [100, 101, 100, 102, 101, 100, 102, 101, 100, 500, 101, 102, 103];
+    let detector = Detector { history: 5, sensitivity: 3.0 };
+    let report = Report::new(&readings, &[], Tolerance::default(), detector);
+    assert_eq!(report.outliers, [9]);
+
+    let stricter = Report::new(
+        &readings,
+        &[],
+        Tolerance::default(),
+        Detector { sensitivity: 0.5, ..detector },
+    );
+    assert!(stricter.outliers.len() > 1);
+}
+
+#[test]
+fn flat_readings_leave_room_for_small_steps() {
+    let readings = [100, 100, 100, 100, 100, 101, 101, 100, 106];
+    let detector = Detector { history: 5, sensitivity: 3.0 };
+    let annotations = sonar::annotate(readings, detector).collect::<Vec<_>>();
+    assert_eq!(annotations[5].deviation, Some(0.0));
+    assert!(!annotations[5].outlier);
+    assert!(!annotations[6].outlier);
+    assert!(annotations[8].outlier);
+}
+
+#[test]
+fn csv_has_a_line_per_reading() {
+    let mut csv = vec![];
+    let detector = Detector { history: 2, sensitivity: 3.0 };
+    sonar::write_csv(sonar::annotate([10, 12, 11, 40], detector), &mut csv).unwrap();
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "index,depth,change,run,median,deviation,outlier\n\
+         0,10,,1,,,false\n\
+         1,12,2,2,,,false\n\
+         2,11,-1,1,11,1,false\n\
+         3,40,29,2,11.5,0.5,true\n"
+    );
+}
@@ -0,0 +1,37 @@
+use day1::{count_increases_with, Tolerance};
+
+/// A depth jittering by 1 around 100 before it climbs, with a few falls on the way.
+const NOISY: [i32; 12] = [100, 101, 100, 101, 100, 101, 105, 104, 105, 110, 106, 111];
+
+#[test]
+fn small_rises_are_not_increases() {
+    let count =
+        |min_delta| count_increases_with(NOISY, 1, Tolerance { min_delta, hysteresis: None });
+    assert_eq!(count(1), 7);
+    assert_eq!(count(2), 3);
+    assert_eq!(count(5), 2);
+    assert_eq!(count(6), 0);
+}
+
+#[test]
+fn increases_wait_for_a_fall() {
+    let count = |hysteresis| {
+        count_increases_with(NOISY, 1, Tolerance { min_delta: 1, hysteresis: Some(hysteresis) })
+    };
+    // Without a fall to wait for, every rise counts.
+    assert_eq!(count(0), count_increases_with(NOISY, 1, Tolerance::default()));
+    // A rise right after another one is the same increase going on.
+    assert_eq!(count(1), 5);
+    assert_eq!(count(2), 2);
+    assert_eq!(count(4), 2);
+    assert_eq!(count(5), 1);
+}
+
+#[test]
+fn tolerances_apply_to_window_sums() {
+    // The sums of two: 201 201 201 201 201 206 209 209 215 216 217.
+    let tolerance = Tolerance { min_delta: 3, hysteresis: None };
+    assert_eq!(count_increases_with(NOISY, 2, tolerance), 3);
+    let tolerance = Tolerance { hysteresis: Some(2), ..tolerance };
+    assert_eq!(count_increases_with(NOISY, 2, tolerance), 1);
+}
@@ -0,0 +1,98 @@
+use common::{Answer, Error, Result, Solution, Source};
+use maplit::hashmap;
+use std::collections::HashMap;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Line {
+    /// First closing character that does not match its opening.
+    Corrupted(char),
+    /// Closing characters still expected, innermost last.
+    Incomplete(Vec<char>),
+}
+
+impl Line {
+    fn check(line: &str) -> Self {
+        let openings = hashmap! {
+            '<' => '>',
+            '{' => '}',
+            '[' => ']',
+            '(' => ')'
+        };
+
+        let closings: HashMap<char, char> = openings.iter().map(|(&x, &y)| (y, x)).collect();
+
+        let mut stack = vec![];
+
+        for c in line.chars() {
+            if let Some(&closing) = openings.get(&c) {
+                stack.push(closing)
+            } else if closings.contains_key(&c) {
+                match stack.pop() {
+                    Some(expected_closing) if c == expected_closing => {}
+                    _ => return Line::Corrupted(c),
+                }
+            }
+        }
+        Line::Incomplete(stack)
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Model = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let source = Source::new(input);
+        Ok(source.records(input.lines(), |line| match line.find(|c| !"()[]{}<>".contains(c)) {
+            Some(i) => Err(source.unexpected(&line[i..], "a bracket")),
+            None => Ok(Line::check(line)),
+        })?)
+    }
+
+    fn part1(lines: &Self::Model) -> Result<Answer> {
+        let scores = hashmap! {
+            ')' => 3,
+            ']' => 57,
+            '}' => 1197,
+            '>' => 25137
+        };
+
+        let score: usize = common::cancel::checked(lines)
+            .filter_map(|line| match line {
+                Line::Corrupted(c) => scores.get(c),
+                Line::Incomplete(_) => None,
+            })
+            .sum();
+        common::cancel::check()?;
+        Ok(score.into())
+    }
+
+    fn part2(lines: &Self::Model) -> Result<Answer> {
+        let scores = hashmap! {
+            ')' => 1,
+            ']' => 2,
+            '}' => 3,
+            '>' => 4
+        };
+
+        let mut scores_by_line = common::cancel::checked(lines)
+            .filter_map(|line| match line {
+                Line::Corrupted(_) => None,
+                Line::Incomplete(stack) => Some(
+                    stack
+                        .iter()
+                        .rev()
+                        .try_fold(0usize, |score, c| score.checked_mul(5)?.checked_add(scores[c]))
+                        .ok_or_else(|| Error::NoSolution("the score overflows".to_string())),
+                ),
+            })
+            .collect::<Result<Vec<usize>>>()?;
+        common::cancel::check()?;
+        scores_by_line.sort_unstable();
+        let final_score = scores_by_line
+            .get(scores_by_line.len() / 2)
+            .ok_or_else(|| Error::NoSolution("no incomplete line".to_string()))?;
+        Ok((*final_score).into())
+    }
+}
@@ -0,0 +1,14 @@
+use common::Solution;
+use day10::Day10;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day10::solve_part1(EXAMPLE), Ok(26397.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day10::solve_part2(EXAMPLE), Ok(288957.into()));
+}
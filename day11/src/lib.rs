@@ -0,0 +1,99 @@
+use common::{Answer, Error, Result, Solution};
+use grid::{Frame, Grid, Neighbourhood, Pixel};
+use std::collections::HashSet;
+
+fn step(grid: &mut Grid<u8>) -> usize {
+    let mut flashes_to_process = vec![];
+
+    for position in grid.positions() {
+        grid[position] += 1;
+        if grid[position] == 10 {
+            flashes_to_process.push(position);
+        }
+    }
+
+    let mut flashes = 0;
+
+    while let Some(position) = flashes_to_process.pop() {
+        flashes += 1;
+        for neighbour in grid.neighbours(position, Neighbourhood::All).collect::<Vec<_>>() {
+            grid[neighbour] += 1;
+            if grid[neighbour] == 10 {
+                flashes_to_process.push(neighbour);
+            }
+        }
+    }
+
+    for item in grid.cells_mut() {
+        if *item > 9 {
+            *item = 0;
+        }
+    }
+
+    flashes
+}
+
+/// Octopuses that just flashed in white, the others darker blue the less energy they have.
+fn pixel(&energy: &u8) -> Pixel {
+    let symbol = char::from(b'0' + energy.min(9));
+    match energy {
+        0 => Pixel::new(symbol, [255, 255, 255]),
+        _ => Pixel::new(symbol, [0, 16 * energy, 60 + 20 * energy]),
+    }
+}
+
+pub struct Day11;
+
+impl Day11 {
+    /// The octopuses at each step, until they all flash together or their energy levels repeat.
+    pub fn frames(grid: &Grid<u8>) -> impl Iterator<Item = Frame> {
+        let mut next = Some((grid.clone(), false));
+        let mut seen = HashSet::new();
+        std::iter::from_fn(move || {
+            let (grid, synchronized) = next.take()?;
+            let frame = grid.map(pixel);
+            if !synchronized && seen.insert(grid.clone()) {
+                let mut stepped = grid;
+                let flashes = step(&mut stepped);
+                let synchronized = flashes == stepped.width() * stepped.height();
+                next = Some((stepped, synchronized));
+            }
+            Some(frame)
+        })
+    }
+}
+
+impl Solution for Day11 {
+    type Model = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Ok(Grid::parse_digits(input)?)
+    }
+
+    fn part1(grid: &Self::Model) -> Result<Answer> {
+        let mut grid = grid.clone();
+        let mut flashes = 0;
+        for _ in 0..100 {
+            common::cancel::check()?;
+            flashes += step(&mut grid);
+        }
+
+        Ok(flashes.into())
+    }
+
+    fn part2(grid: &Self::Model) -> Result<Answer> {
+        let mut grid = grid.clone();
+        let octopuses = grid.width() * grid.height();
+        // Steps are deterministic: once the energy levels repeat, they never all flash.
+        let mut seen = HashSet::new();
+        let mut n = 0usize;
+        while seen.insert(grid.clone()) {
+            common::cancel::check()?;
+            n += 1;
+            if step(&mut grid) == octopuses {
+                return Ok(n.into());
+            }
+        }
+        Err(Error::NoSolution("the octopuses never all flash together".to_string()))
+    }
+}
@@ -0,0 +1,27 @@
+use common::{Error, Solution};
+use day11::Day11;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day11::solve_part1(EXAMPLE), Ok(1656.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day11::solve_part2(EXAMPLE), Ok(195.into()));
+}
+
+#[test]
+fn part2_never_synchronized() {
+    assert!(matches!(Day11::solve_part2("05\n"), Err(Error::NoSolution(_))));
+}
+
+#[test]
+fn frames_until_synchronized() {
+    let grid = Day11::parse(EXAMPLE).unwrap();
+    let last = Day11::frames(&grid).last().unwrap();
+    assert_eq!(Day11::frames(&grid).count(), 196);
+    assert!(last.to_text().chars().all(|c| c == '0' || c == '\n'));
+}
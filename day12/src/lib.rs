@@ -0,0 +1,99 @@
+use common::{Answer, Result, Solution, Source};
+use std::collections::HashMap;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Caves {
+    small: Vec<bool>,
+    paths: Vec<Vec<usize>>,
+    start: usize,
+    end: usize,
+}
+
+impl Caves {
+    fn visit(&self, current: usize, visited: &mut [u8], small_cave_twice: bool) -> Result<usize> {
+        common::cancel::check()?;
+        let mut counter = 0;
+        for &destination in &self.paths[current] {
+            if destination == self.start {
+                continue;
+            }
+            if destination == self.end {
+                counter += 1;
+                continue;
+            }
+            let small_cave_twice = match (self.small[destination], visited[destination]) {
+                (false, _) | (true, 0) => small_cave_twice,
+                (true, 1) if !small_cave_twice => true,
+                _ => continue,
+            };
+            visited[destination] += 1;
+            counter += self.visit(destination, visited, small_cave_twice)?;
+            visited[destination] -= 1;
+        }
+        Ok(counter)
+    }
+
+    fn count_paths(&self, small_cave_twice: bool) -> Result<usize> {
+        let mut visited = vec![0u8; self.paths.len()];
+        self.visit(self.start, &mut visited, small_cave_twice)
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Model = Caves;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let mut ids = HashMap::new();
+        let mut caves = Caves { small: vec![], paths: vec![], start: 0, end: 0 };
+
+        let mut id = |name: &str, caves: &mut Caves| {
+            *ids.entry(name.to_string()).or_insert_with(|| {
+                caves.small.push(name.chars().all(|c| c.is_ascii_lowercase()));
+                caves.paths.push(vec![]);
+                caves.paths.len() - 1
+            })
+        };
+
+        let source = Source::new(input);
+        let cave = |name: &str| {
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(source.error(name, "a cave name"));
+            }
+            Ok(())
+        };
+        let connections = source.records(input.lines(), |line| {
+            let (from, to) = source.split_once(line, "-")?;
+            cave(from)?;
+            cave(to)?;
+            Ok((line, from, to))
+        })?;
+
+        // Two big caves next to each other could be gone back and forth between forever. Even when
+        // lenient, such a path is not skipped, as the count would quietly change.
+        let big = |name: &str| !name.chars().all(|c| c.is_ascii_lowercase());
+        if let Some(&(line, _, _)) = connections.iter().find(|(_, from, to)| big(from) && big(to)) {
+            return Err(source.error(line, "a path with a small cave").into());
+        }
+
+        for (_, from, to) in connections {
+            let from = id(from, &mut caves);
+            let to = id(to, &mut caves);
+            caves.paths[from].push(to);
+            caves.paths[to].push(from);
+        }
+
+        caves.start = id("start", &mut caves);
+        caves.end = id("end", &mut caves);
+        Ok(caves)
+    }
+
+    fn part1(caves: &Self::Model) -> Result<Answer> {
+        Ok(caves.count_paths(true)?.into())
+    }
+
+    fn part2(caves: &Self::Model) -> Result<Answer> {
+        Ok(caves.count_paths(false)?.into())
+    }
+}
@@ -0,0 +1,58 @@
+use common::{Error, Solution};
+use day12::Day12;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+const LARGER_EXAMPLE: &str = "\
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sj
+kj-HN
+kj-dc
+";
+
+const EVEN_LARGER_EXAMPLE: &str = "\
+fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW
+";
+
+#[test]
+fn part1_examples() {
+    assert_eq!(Day12::solve_part1(EXAMPLE), Ok(10.into()));
+    assert_eq!(Day12::solve_part1(LARGER_EXAMPLE), Ok(19.into()));
+    assert_eq!(Day12::solve_part1(EVEN_LARGER_EXAMPLE), Ok(226.into()));
+}
+
+#[test]
+fn part2_examples() {
+    assert_eq!(Day12::solve_part2(EXAMPLE), Ok(36.into()));
+    assert_eq!(Day12::solve_part2(LARGER_EXAMPLE), Ok(103.into()));
+    assert_eq!(Day12::solve_part2(EVEN_LARGER_EXAMPLE), Ok(3509.into()));
+}
+
+#[test]
+fn big_caves_next_to_each_other_are_rejected() {
+    let error = Day12::parse("start-A\nA-B\nB-end\n").err().unwrap();
+    assert!(matches!(error, Error::Parse(ref e) if e.line == 2), "{:?}", error);
+}
@@ -0,0 +1,187 @@
+use common::{input, Answer, Error, Result, Solution, Source};
+use grid::{Frame, Grid, Pixel};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Axis {
+    X,
+    Y,
+}
+
+fn fold(map: &mut HashSet<(i32, i32)>, direction: Axis, at: i32) {
+    let folded_dots = map
+        .iter()
+        .filter(|(x, y)| match direction {
+            Axis::X => *x >= at,
+            Axis::Y => *y >= at,
+        })
+        .copied()
+        .collect_vec();
+
+    for (x, y) in folded_dots {
+        map.remove(&(x, y));
+        let target = match direction {
+            Axis::X => (at - (x - at), y),
+            Axis::Y => (x, at - (y - at)),
+        };
+        map.insert(target);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Manual {
+    dots: HashSet<(i32, i32)>,
+    folds: Vec<(Axis, i32)>,
+}
+
+/// The most dots a paper is drawn with, far more than any manual needs.
+const MAX_PAPER: usize = 1 << 24;
+
+/// The paper with `map` dotted on it, just large enough to show every dot. Fails when a fold left
+/// a dot off the paper or when the paper is too large to draw.
+fn paper(map: &HashSet<(i32, i32)>) -> Result<Grid<bool>> {
+    if map.iter().any(|&(x, y)| x < 0 || y < 0) {
+        return Err(Error::NoSolution("a dot was folded off the paper".to_string()));
+    }
+    let max_x = map.iter().map(|(x, _)| *x).max().unwrap_or(0);
+    let max_y = map.iter().map(|(_, y)| *y).max().unwrap_or(0);
+
+    let width = max_x as usize + 1;
+    let height = max_y as usize + 1;
+    if width.checked_mul(height).is_none_or(|size| size > MAX_PAPER) {
+        return Err(Error::NoSolution(format!("the paper is too large: {}x{}", width, height)));
+    }
+    Ok(Grid::from_fn(width, height, |(x, y)| map.contains(&(x as i32, y as i32))))
+}
+
+fn to_pixel(&dot: &bool) -> Pixel {
+    if dot {
+        Pixel::new('#', [255, 200, 40])
+    } else {
+        Pixel::new('.', [30, 30, 30])
+    }
+}
+
+/// The letters of the code, drawn 4 dots wide and 6 high, row after row.
+const LETTERS: [(char, &str); 15] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// Reads the code off the folded paper, one letter every 5 dots with a blank column between
+/// letters. Returns `None` unless every letter is one of [`LETTERS`].
+fn read_letters(paper: &Grid<bool>) -> Option<String> {
+    if paper.height() != 6 {
+        return None;
+    }
+    let dot = |x: usize, y: usize| paper.get((x, y)).copied().unwrap_or(false);
+    (0..paper.width().div_ceil(5))
+        .map(|letter| {
+            let left = letter * 5;
+            if (0..6).any(|y| dot(left + 4, y)) {
+                return None;
+            }
+            let glyph = (0..6)
+                .flat_map(|y| (left..left + 4).map(move |x| (x, y)))
+                .map(|(x, y)| if dot(x, y) { '#' } else { '.' })
+                .collect::<String>();
+            LETTERS.iter().find(|(_, letter)| *letter == glyph).map(|&(letter, _)| letter)
+        })
+        .collect()
+}
+
+pub struct Day13;
+
+impl Day13 {
+    /// The paper before folding, then after each fold.
+    pub fn frames(manual: &Manual) -> Result<impl Iterator<Item = Frame>> {
+        let mut map = manual.dots.clone();
+        let mut frames = vec![paper(&map)?.map(to_pixel)];
+        for &(direction, at) in &manual.folds {
+            fold(&mut map, direction, at);
+            frames.push(paper(&map)?.map(to_pixel));
+        }
+        Ok(frames.into_iter())
+    }
+}
+
+impl Solution for Day13 {
+    type Model = Manual;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let source = Source::new(input);
+        let mut sections = input::sections(input);
+        let dots = sections.next().ok_or_else(|| source.end("the dots"))?;
+        let dots = source.records(dots.lines(), |line| {
+            let (x, y) = source.split_once(line, ",")?;
+            let dot = (source.number(x)?, source.number(y)?);
+            if dot.0 < 0 {
+                return Err(source.error(x, "a coordinate of at least 0"));
+            }
+            if dot.1 < 0 {
+                return Err(source.error(y, "a coordinate of at least 0"));
+            }
+            Ok(dot)
+        })?;
+
+        let folds = source.records(sections.flat_map(str::lines), |line| {
+            let fold = source.strip_prefix(line, "fold along ")?;
+            let (axis, at) = source.split_once(fold, "=")?;
+            let axis = match axis {
+                "x" => Axis::X,
+                "y" => Axis::Y,
+                _ => return Err(source.error(axis, "x or y")),
+            };
+            let line = source.number(at)?;
+            if line < 0 {
+                return Err(source.error(at, "a line at 0 or past it"));
+            }
+            Ok((axis, line))
+        })?;
+
+        Ok(Manual { dots: dots.into_iter().collect(), folds })
+    }
+
+    fn part1(manual: &Self::Model) -> Result<Answer> {
+        let mut map = manual.dots.clone();
+        let &(direction, at) = manual
+            .folds
+            .first()
+            .ok_or_else(|| Error::NoSolution("no fold instruction".to_string()))?;
+        common::cancel::check()?;
+        fold(&mut map, direction, at);
+        Ok(map.len().into())
+    }
+
+    /// The letters of the code when they can all be read, or else the folded paper: its rows of
+    /// `#` and `.` joined by newlines, with no trailing newline.
+    fn part2(manual: &Self::Model) -> Result<Answer> {
+        let mut map = manual.dots.clone();
+        for &(direction, at) in &manual.folds {
+            common::cancel::check()?;
+            fold(&mut map, direction, at)
+        }
+
+        let paper = paper(&map)?;
+        if let Some(letters) = read_letters(&paper) {
+            return Ok(letters.into());
+        }
+        let code = paper.render(|&dot| if dot { '#' } else { '.' });
+        Ok(code.trim_end().into())
+    }
+}
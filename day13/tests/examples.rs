@@ -0,0 +1,56 @@
+use common::{Error, Solution};
+use day13::Day13;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day13::solve_part1(EXAMPLE), Ok(17.into()));
+}
+
+#[test]
+fn part2_example() {
+    let square = "\
+#####
+#...#
+#...#
+#...#
+#####";
+    assert_eq!(Day13::solve_part2(EXAMPLE), Ok(square.into()));
+}
+
+#[test]
+fn frames_after_each_fold() {
+    let manual = Day13::parse(EXAMPLE).unwrap();
+    let frames = Day13::frames(&manual).unwrap().collect::<Vec<_>>();
+    assert_eq!(frames.len(), 3);
+    assert_eq!(frames[2].to_text(), "#####\n#...#\n#...#\n#...#\n#####\n");
+}
+
+#[test]
+fn part2_reads_the_letters() {
+    let code = "\
+.##..###.
+#..#.#..#
+#..#.###.
+####.#..#
+#..#.#..#
+#..#.###.";
+    let mut input = String::new();
+    for (y, row) in code.lines().enumerate() {
+        for (x, _) in row.match_indices('#') {
+            // Folded back onto the code along x=20.
+            input.push_str(&format!("{},{}\n", 40 - x, y));
+        }
+    }
+    input.push_str("\nfold along x=20\n");
+    assert_eq!(Day13::solve_part2(&input), Ok("AB".into()));
+}
+
+#[test]
+fn paper_too_large_to_draw() {
+    assert!(matches!(
+        Day13::solve_part2("2000000000,0\n0,1\n\nfold along y=5\n"),
+        Err(Error::NoSolution(_))
+    ));
+}
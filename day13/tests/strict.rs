@@ -0,0 +1,11 @@
+use common::{Error, ParseError, Solution};
+use day13::Day13;
+
+#[test]
+fn negative_dots_are_rejected() {
+    common::parse::set_strict(true);
+    assert_eq!(
+        Day13::parse("1,-2\n\nfold along y=1\n").err(),
+        Some(Error::Parse(ParseError::new(1, 3, "a coordinate of at least 0", "-2"))),
+    );
+}
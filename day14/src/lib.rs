@@ -0,0 +1,162 @@
+use common::{Answer, Output, Result, Solution, Source};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::mem;
+use std::rc::Rc;
+
+struct Node {
+    element: char,
+    next: Option<Rc<RefCell<Node>>>,
+}
+
+impl Node {
+    fn pair(&self) -> Option<(char, char)> {
+        self.next.as_ref().map(|next| (self.element, RefCell::borrow(next).element))
+    }
+
+    fn insert_after(&mut self, element: char) {
+        let old = mem::take(&mut self.next);
+        self.next = Some(Rc::new(RefCell::new(Node { element, next: old })));
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polymer {
+    template: Vec<char>,
+    #[cfg_attr(feature = "serde", serde(with = "common::pairs"))]
+    rules: HashMap<(char, char), char>,
+}
+
+impl Polymer {
+    /// Grows the polymer element by element in a linked list, which doubles in length at each
+    /// step.
+    pub fn grow(&self, steps: usize) -> Result<HashMap<char, usize>> {
+        let mut counts = HashMap::<char, usize>::new();
+        let mut line = self.template.iter();
+
+        let start = line.next().map(|&element| Rc::new(RefCell::new(Node { element, next: None })));
+        let mut current = start.clone();
+
+        for &element in self.template.iter() {
+            *counts.entry(element).or_default() += 1;
+        }
+        for &element in line {
+            if let Some(node) = current.clone() {
+                RefCell::borrow_mut(&node).insert_after(element);
+                current = RefCell::borrow(&node).next.clone();
+            }
+        }
+
+        for _ in 0..steps {
+            common::cancel::check()?;
+            current = start.clone();
+
+            while let Some(node) = current {
+                let pair = RefCell::borrow(&node).pair();
+                match pair.and_then(|pair| self.rules.get(&pair)) {
+                    Some(&element) => {
+                        RefCell::borrow_mut(&node).insert_after(element);
+                        *counts.entry(element).or_default() += 1;
+                        let inserted = RefCell::borrow(&node).next.clone().unwrap();
+                        current = RefCell::borrow(&inserted).next.clone();
+                    }
+                    None => current = RefCell::borrow(&node).next.clone(),
+                }
+            }
+        }
+
+        Ok(counts)
+    }
+
+    /// Only tracks how many times each pair occurs, which is enough to count elements.
+    pub fn grow_pairs(&self, steps: usize) -> Result<HashMap<char, usize>> {
+        let mut counts = HashMap::<char, usize>::new();
+        let mut pairs = HashMap::<(char, char), usize>::new();
+
+        for &element in &self.template {
+            *counts.entry(element).or_default() += 1;
+        }
+        for pair in self.template.windows(2) {
+            *pairs.entry((pair[0], pair[1])).or_default() += 1;
+        }
+
+        for _ in 0..steps {
+            common::cancel::check()?;
+            let mut next_pairs = HashMap::new();
+            for ((element_1, element_2), occurences) in pairs {
+                match self.rules.get(&(element_1, element_2)) {
+                    Some(&new_element) => {
+                        *counts.entry(new_element).or_default() += occurences;
+                        *next_pairs.entry((element_1, new_element)).or_default() += occurences;
+                        *next_pairs.entry((new_element, element_2)).or_default() += occurences;
+                    }
+                    None => *next_pairs.entry((element_1, element_2)).or_default() += occurences,
+                }
+            }
+            pairs = next_pairs;
+        }
+
+        Ok(counts)
+    }
+}
+
+/// The most common element count minus the least common one.
+fn answer(counts: HashMap<char, usize>) -> Output {
+    let mut min = usize::MAX;
+    let mut max = 0;
+
+    for &count in counts.values() {
+        min = min.min(count);
+        max = max.max(count);
+    }
+    Output::from(max.saturating_sub(min)).with("max", max).with("min", min)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Model = Polymer;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let source = Source::new(input);
+        let mut lines = input.lines();
+
+        let template = lines.next().ok_or_else(|| source.end("a polymer template"))?;
+        if let Some(i) = template.find(|c: char| !c.is_ascii_uppercase()) {
+            return Err(source.unexpected(&template[i..], "an element").into());
+        }
+        if template.is_empty() {
+            return Err(source.missing(template, "a polymer template").into());
+        }
+
+        let elements = |s: &str, count: usize, expected: &str| {
+            if s.chars().count() != count || !s.chars().all(|c| c.is_ascii_uppercase()) {
+                return Err(source.error(s, expected));
+            }
+            Ok(s.chars().collect::<Vec<_>>())
+        };
+        let rules = source.records(lines.skip(1), |line| {
+            let (pair, inserted) = source.split_once(line, " -> ")?;
+            let pair = elements(pair, 2, "a pair of elements")?;
+            Ok(((pair[0], pair[1]), elements(inserted, 1, "an element")?[0]))
+        })?;
+
+        Ok(Polymer { template: template.chars().collect(), rules: rules.into_iter().collect() })
+    }
+
+    fn part1(polymer: &Self::Model) -> Result<Answer> {
+        Self::part1_output(polymer).map(|output| output.answer)
+    }
+
+    fn part2(polymer: &Self::Model) -> Result<Answer> {
+        Self::part2_output(polymer).map(|output| output.answer)
+    }
+
+    fn part1_output(polymer: &Self::Model) -> Result<Output> {
+        Ok(answer(polymer.grow(10)?))
+    }
+
+    fn part2_output(polymer: &Self::Model) -> Result<Output> {
+        Ok(answer(polymer.grow_pairs(40)?))
+    }
+}
@@ -0,0 +1,29 @@
+use common::Solution;
+use day14::Day14;
+use proptest::prelude::*;
+
+/// A template and insertion rules over a few elements, so that rules apply often.
+fn polymer() -> impl Strategy<Value = String> {
+    let element = prop::sample::select(vec!['B', 'C', 'H', 'N']);
+    let template = prop::collection::vec(element.clone(), 1..8);
+    let rules = prop::collection::vec(prop::option::of(element.clone()), 16);
+    (template, rules).prop_map(|(template, rules)| {
+        let mut input = template.into_iter().collect::<String>() + "\n\n";
+        let elements = ['B', 'C', 'H', 'N'];
+        for (i, inserted) in rules.into_iter().enumerate() {
+            if let Some(inserted) = inserted {
+                let (a, b) = (elements[i / 4], elements[i % 4]);
+                input.push_str(&format!("{}{} -> {}\n", a, b, inserted));
+            }
+        }
+        input
+    })
+}
+
+proptest! {
+    #[test]
+    fn counting_pairs_agrees_with_growing(input in polymer(), steps in 0..8usize) {
+        let polymer = Day14::parse(&input).unwrap();
+        prop_assert_eq!(polymer.grow_pairs(steps), polymer.grow(steps));
+    }
+}
@@ -0,0 +1,14 @@
+use common::Solution;
+use day14::Day14;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day14::solve_part1(EXAMPLE), Ok(1588.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day14::solve_part2(EXAMPLE), Ok(2188189693529i64.into()));
+}
@@ -0,0 +1,80 @@
+use common::{Answer, Error, Output, Result, Solution};
+use grid::{Grid, Neighbourhood, Position};
+use search::State;
+
+/// Standing somewhere in the cave.
+struct Visit<'a> {
+    map: &'a Grid<u8>,
+    position: Position,
+}
+
+impl State for Visit<'_> {
+    type Key = Position;
+    type Cost = usize;
+
+    fn key(&self) -> Position {
+        self.position
+    }
+
+    fn successors(&self) -> Vec<(Self, usize)> {
+        self.map
+            .neighbours(self.position, Neighbourhood::Orthogonal)
+            .map(|position| (Visit { map: self.map, position }, self.map[position] as usize))
+            .collect()
+    }
+
+    fn is_goal(&self) -> bool {
+        self.position == (self.map.width() - 1, self.map.height() - 1)
+    }
+
+    /// Every step risks at least 1.
+    fn heuristic(&self) -> usize {
+        let (x, y) = self.position;
+        (self.map.width() - 1 - x) + (self.map.height() - 1 - y)
+    }
+}
+
+fn lowest_total_risk(map: &Grid<u8>) -> Result<Output> {
+    let outcome = search::search([Visit { map, position: (0, 0) }])?;
+    let found = outcome.found.ok_or_else(|| Error::NoSolution("no path out".to_string()))?;
+    Ok(Output::from(found.cost).with("expanded", outcome.stats.expanded))
+}
+
+/// The full cave is the tile repeated five times in both directions, risks increasing with the
+/// distance to the original tile.
+fn expand(map: &Grid<u8>) -> Grid<u8> {
+    let (width, height) = (map.width(), map.height());
+
+    Grid::from_fn(width * 5, height * 5, |(x, y)| {
+        let risk = map[(x % width, y % height)];
+        (risk - 1 + (x / width) as u8 + (y / height) as u8) % 9 + 1
+    })
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Model = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Ok(Grid::parse(input, "a digit from 1 to 9", |c| {
+            c.to_digit(10).filter(|&risk| risk > 0).map(|risk| risk as u8)
+        })?)
+    }
+
+    fn part1(map: &Self::Model) -> Result<Answer> {
+        Self::part1_output(map).map(|output| output.answer)
+    }
+
+    fn part2(map: &Self::Model) -> Result<Answer> {
+        Self::part2_output(map).map(|output| output.answer)
+    }
+
+    fn part1_output(map: &Self::Model) -> Result<Output> {
+        lowest_total_risk(map)
+    }
+
+    fn part2_output(map: &Self::Model) -> Result<Output> {
+        lowest_total_risk(&expand(map))
+    }
+}
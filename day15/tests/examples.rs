@@ -0,0 +1,14 @@
+use common::Solution;
+use day15::Day15;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day15::solve_part1(EXAMPLE), Ok(40.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day15::solve_part2(EXAMPLE), Ok(315.into()));
+}
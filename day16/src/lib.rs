@@ -0,0 +1,201 @@
+use common::{Answer, Error, ParseError, Result, Solution, Source};
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PacketContents {
+    Value(usize),
+    Sum(Vec<Packet>),
+    Product(Vec<Packet>),
+    Minimum(Vec<Packet>),
+    Maximum(Vec<Packet>),
+    GreaterThan(Vec<Packet>),
+    LessThan(Vec<Packet>),
+    EqualTo(Vec<Packet>),
+}
+
+/// Bit at which a packet stops making sense, and what was expected there.
+#[derive(Clone, Copy)]
+struct Malformed {
+    bit: usize,
+    expected: &'static str,
+}
+
+/// Far deeper than any transmission, shallow enough not to overflow the stack.
+const MAX_DEPTH: usize = 256;
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Packet {
+    version: usize,
+    contents: PacketContents,
+}
+
+impl Packet {
+    fn from_sub_packets(version: usize, type_id: usize, sub_packets: Vec<Packet>) -> Self {
+        let contents = match type_id {
+            0 => PacketContents::Sum(sub_packets),
+            1 => PacketContents::Product(sub_packets),
+            2 => PacketContents::Minimum(sub_packets),
+            3 => PacketContents::Maximum(sub_packets),
+            5 => PacketContents::GreaterThan(sub_packets),
+            6 => PacketContents::LessThan(sub_packets),
+            7 => PacketContents::EqualTo(sub_packets),
+            _ => unreachable!(),
+        };
+
+        Self { version, contents }
+    }
+
+    /// Decodes the packet at the start of `line`, a string of bits, and how many bits it uses.
+    fn from_line(line: &str, depth: usize) -> std::result::Result<(Self, usize), Malformed> {
+        let truncated = Malformed { bit: line.len(), expected: "the rest of the packet" };
+        let malformed = |expected| Err(Malformed { bit: 0, expected });
+        let bits = |from: usize, count: usize| {
+            line.get(from..from + count)
+                .and_then(|bits| usize::from_str_radix(bits, 2).ok())
+                .ok_or(truncated)
+        };
+        if depth > MAX_DEPTH {
+            return malformed("packets nested at most 256 deep");
+        }
+        let version = bits(0, 3)?;
+        let packet_type = bits(3, 3)?;
+
+        match packet_type {
+            4 => {
+                let mut value: usize = 0;
+                let mut length = 6;
+                loop {
+                    let group = bits(length, 5)?;
+                    length += 5;
+                    if value.leading_zeros() < 4 {
+                        return malformed("a literal value that fits in 64 bits");
+                    }
+                    value = (value << 4) + (group & 0b1111);
+                    if group & 0b10000 == 0 {
+                        break;
+                    }
+                }
+
+                Ok((Packet { version, contents: PacketContents::Value(value) }, length))
+            }
+            _ => {
+                let sub_packet = |start: usize| {
+                    Self::from_line(&line[start..], depth + 1)
+                        .map_err(|e| Malformed { bit: start + e.bit, ..e })
+                };
+                let (subpackets, length) = if bits(6, 1)? == 0 {
+                    let length = bits(7, 15)?;
+                    let mut processed = 0;
+                    let mut subpackets = vec![];
+                    while processed < length {
+                        let (subpacket, subpacket_length) = sub_packet(22 + processed)?;
+                        subpackets.push(subpacket);
+                        processed += subpacket_length;
+                    }
+                    if processed > length {
+                        return malformed("sub-packets filling the length of their packet");
+                    }
+                    (subpackets, 22 + length)
+                } else {
+                    let occurrences = bits(7, 11)?;
+                    let mut length_processed = 0;
+                    let mut subpackets = vec![];
+
+                    for _ in 0..occurrences {
+                        let (subpacket, subpacket_length) = sub_packet(18 + length_processed)?;
+                        subpackets.push(subpacket);
+                        length_processed += subpacket_length;
+                    }
+                    (subpackets, 18 + length_processed)
+                };
+
+                match (packet_type, subpackets.len()) {
+                    (_, 0) => malformed("an operator with sub-packets"),
+                    (5..=7, count) if count != 2 => malformed("a comparison of two sub-packets"),
+                    _ => Ok((Packet::from_sub_packets(version, packet_type, subpackets), length)),
+                }
+            }
+        }
+    }
+
+    fn sub_packets(&self) -> &[Packet] {
+        match &self.contents {
+            PacketContents::Value(_) => &[],
+            PacketContents::Sum(packets)
+            | PacketContents::Product(packets)
+            | PacketContents::Minimum(packets)
+            | PacketContents::Maximum(packets)
+            | PacketContents::GreaterThan(packets)
+            | PacketContents::LessThan(packets)
+            | PacketContents::EqualTo(packets) => packets,
+        }
+    }
+
+    fn sum_versions(&self) -> usize {
+        self.version + self.sub_packets().iter().map(Self::sum_versions).sum::<usize>()
+    }
+
+    /// The value of the packet, an error if it overflows.
+    fn get_value(&self) -> Result<usize> {
+        common::cancel::check()?;
+        let overflow = || Error::NoSolution("the value overflows".to_string());
+        let values = |packets: &[Packet]| {
+            packets.iter().map(Self::get_value).collect::<Result<Vec<_>>>().map(Vec::into_iter)
+        };
+        let compare = |packets: &[Packet], ordering: fn(&usize, &usize) -> bool| {
+            let values = values(packets)?.collect::<Vec<_>>();
+            Ok(ordering(&values[0], &values[1]) as usize)
+        };
+        match &self.contents {
+            PacketContents::Value(value) => Ok(*value),
+            PacketContents::Sum(packets) => {
+                values(packets)?.try_fold(0, usize::checked_add).ok_or_else(overflow)
+            }
+            PacketContents::Product(packets) => {
+                values(packets)?.try_fold(1, usize::checked_mul).ok_or_else(overflow)
+            }
+            PacketContents::Minimum(packets) => values(packets)?.min().ok_or_else(overflow),
+            PacketContents::Maximum(packets) => values(packets)?.max().ok_or_else(overflow),
+            PacketContents::GreaterThan(packets) => compare(packets, usize::gt),
+            PacketContents::LessThan(packets) => compare(packets, usize::lt),
+            PacketContents::EqualTo(packets) => compare(packets, usize::eq),
+        }
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Model = Vec<Packet>;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let source = Source::new(input);
+        Ok(source.records(input.lines(), |line| {
+            let bits = line
+                .char_indices()
+                .map(|(i, c)| {
+                    c.to_digit(16)
+                        .map(|digit| format!("{:04b}", digit))
+                        .ok_or_else(|| source.unexpected(&line[i..], "a hexadecimal digit"))
+                })
+                .collect::<std::result::Result<String, ParseError>>()?;
+            let (packet, _) = Packet::from_line(&bits, 0).map_err(|e| {
+                let i = (e.bit / 4).min(line.len());
+                source.unexpected(&line[i..], e.expected)
+            })?;
+            Ok(packet)
+        })?)
+    }
+
+    fn part1(packets: &Self::Model) -> Result<Answer> {
+        let versions = common::cancel::checked(packets).map(Packet::sum_versions).sum::<usize>();
+        common::cancel::check()?;
+        Ok(versions.into())
+    }
+
+    fn part2(packets: &Self::Model) -> Result<Answer> {
+        let packet = packets.first().ok_or_else(|| Error::NoSolution("no packet".to_string()))?;
+        Ok(packet.get_value()?.into())
+    }
+}
@@ -0,0 +1,49 @@
+use common::{Error, ParseError, Solution};
+use day16::Day16;
+
+#[test]
+fn truncated_packet() {
+    common::parse::set_strict(true);
+    for (transmission, column) in [("D2FE2", 6), ("38006F4529", 11)] {
+        assert_eq!(
+            Day16::parse(transmission).err(),
+            Some(Error::Parse(ParseError::new(1, column, "the rest of the packet", ""))),
+            "{}",
+            transmission
+        );
+    }
+}
+
+#[test]
+fn malformed_packets() {
+    common::parse::set_strict(true);
+    for (transmission, column, expected, found) in [
+        ("02000", 1, "an operator with sub-packets", "0"),
+        ("16004408", 1, "a comparison of two sub-packets", "1"),
+        ("13FFFFFFFFFFFFFFFFFFFC2", 1, "a literal value that fits in 64 bits", "1"),
+        ("00003C40881", 1, "sub-packets filling the length of their packet", "0"),
+    ] {
+        assert_eq!(
+            Day16::parse(transmission).err(),
+            Some(Error::Parse(ParseError::new(1, column, expected, found))),
+            "{}",
+            transmission
+        );
+    }
+}
+
+#[test]
+fn deeply_nested_packets() {
+    common::parse::set_strict(true);
+    // Sum packets holding one sub-packet each, 18 bits at a time.
+    let bits = "000000100000000001".repeat(1000);
+    let transmission = bits
+        .as_bytes()
+        .chunks(4)
+        .map(|nibble| {
+            let nibble = std::str::from_utf8(nibble).unwrap();
+            format!("{:X}", u8::from_str_radix(&format!("{:0<4}", nibble), 2).unwrap())
+        })
+        .collect::<String>();
+    assert!(matches!(Day16::parse(&transmission), Err(Error::Parse(_))));
+}
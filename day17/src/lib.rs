@@ -0,0 +1,128 @@
+use common::{Answer, Error, Result, Solution, Source};
+use geometry::{Rect, Vec2};
+use std::cmp::Ordering;
+
+/// Where the probe must end up.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Target(Rect);
+
+#[derive(Debug)]
+struct Probe {
+    position: Vec2,
+    velocity: Vec2,
+}
+
+enum Reachability {
+    Unreachable,
+    Reachable,
+    Hit,
+}
+
+impl Probe {
+    fn advance(&mut self) {
+        self.position += self.velocity;
+
+        self.velocity.x -= self.velocity.x.signum();
+        self.velocity.y -= 1;
+    }
+
+    fn reachability(&self, Target(area): &Target) -> Reachability {
+        let Vec2 { x, y } = self.position;
+        if area.contains(self.position) {
+            Reachability::Hit
+        } else if match self.velocity.x.cmp(&0) {
+            Ordering::Equal => {
+                if !(area.min.x..=area.max.x).contains(&x) {
+                    false
+                } else if y >= area.min.y {
+                    true
+                } else {
+                    self.velocity.y > 0
+                }
+            }
+            Ordering::Less => x >= area.min.x,
+            Ordering::Greater => x <= area.max.x,
+        } {
+            Reachability::Reachable
+        } else {
+            Reachability::Unreachable
+        }
+    }
+}
+
+impl Target {
+    /// Highest point reached by every initial velocity that ends up in the target.
+    fn hits(&self) -> Result<Vec<i64>> {
+        let Target(area) = self;
+        let mut hits = vec![];
+
+        for x_velocity in 0..area.max.x * 2 {
+            common::cancel::check()?;
+            let mut y_velocity = area.min.y.min(0);
+
+            loop {
+                let mut probe =
+                    Probe { position: Vec2::ZERO, velocity: Vec2::new(x_velocity, y_velocity) };
+
+                let mut max_y = probe.position.y;
+
+                loop {
+                    match probe.reachability(self) {
+                        Reachability::Unreachable => break,
+                        Reachability::Reachable => {}
+                        Reachability::Hit => {
+                            hits.push(max_y);
+                            break;
+                        }
+                    }
+                    probe.advance();
+                    max_y = max_y.max(probe.position.y);
+                }
+
+                if probe.position.x < area.min.x {
+                    break;
+                }
+
+                if y_velocity > 1000 {
+                    // lame breaking condition but hey it works
+                    break;
+                }
+
+                y_velocity += 1;
+            }
+        }
+
+        Ok(hits)
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Model = Target;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let source = Source::new(input);
+        let line = input.trim_end();
+        let ranges = source.strip_prefix(line, "target area: ")?;
+        let [x_min, x_max, y_min, y_max] = source.integers(ranges)?;
+        if x_min > x_max || y_min > y_max {
+            return Err(source.error(ranges, "increasing ranges").into());
+        }
+
+        Ok(Target(Rect::new(Vec2::new(x_min, y_min), Vec2::new(x_max, y_max))))
+    }
+
+    fn part1(target: &Self::Model) -> Result<Answer> {
+        let best_y = target
+            .hits()?
+            .into_iter()
+            .max()
+            .ok_or_else(|| Error::NoSolution("no velocity hits the target".to_string()))?;
+        Ok(best_y.into())
+    }
+
+    fn part2(target: &Self::Model) -> Result<Answer> {
+        Ok(target.hits()?.len().into())
+    }
+}
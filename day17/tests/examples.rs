@@ -0,0 +1,30 @@
+use common::Solution;
+use day17::Day17;
+
+const EXAMPLE: &str = "target area: x=20..30, y=-10..-5\n";
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day17::solve_part1(EXAMPLE), Ok(45.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day17::solve_part2(EXAMPLE), Ok(112.into()));
+}
+
+#[test]
+fn deep_target() {
+    // the probe comes back down through y = 0 at the launch speed, so the best shot
+    // reaches the bottom of the target in one step
+    let target = "target area: x=20..30, y=-100..-90";
+    assert_eq!(Day17::solve_part1(target), Ok(4950.into()));
+    assert_eq!(Day17::solve_part2(target), Ok(280.into()));
+}
+
+#[test]
+fn target_above_the_launcher() {
+    let target = "target area: x=20..30, y=5..10";
+    assert_eq!(Day17::solve_part1(target), Ok(55.into()));
+    assert_eq!(Day17::solve_part2(target), Ok(103.into()));
+}
@@ -0,0 +1,61 @@
+//! Snailfish numbers written as nested arrays, as in the homework.
+
+use crate::{Node, Snailfish};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::cell::RefCell;
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+
+impl Serialize for Node {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            &Node::Number(n, _) => serializer.serialize_i32(n),
+            Node::Pair(parts, _) => serializer.collect_seq(parts.iter().map(Rc::deref)),
+        }
+    }
+}
+
+impl Serialize for Snailfish {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum Element {
+    Number(i32),
+    Pair(Box<Element>, Box<Element>),
+}
+
+/// Adds `element` to the pair `parent`, linking every new node to its own parent.
+fn build(element: Element, parent: &Rc<RefCell<Node>>) {
+    match element {
+        Element::Number(n) => {
+            Node::add_number(parent, n);
+        }
+        Element::Pair(left, right) => {
+            let node = Node::add_empty_node(Some(parent));
+            build(*left, &node);
+            build(*right, &node);
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Snailfish {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let element = Element::deserialize(deserializer)?;
+        if let Element::Number(_) = element {
+            return Err(de::Error::custom("a snailfish number is a pair"));
+        }
+        let seed = Node::add_empty_node(None);
+        build(element, &seed);
+        let root = if let Node::Pair(parts, _) = RefCell::borrow_mut(&seed).deref_mut() {
+            parts.pop().unwrap()
+        } else {
+            unreachable!()
+        };
+        Ok(Snailfish(root))
+    }
+}
@@ -0,0 +1,33 @@
+use common::Solution;
+use day18::Day18;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day18::solve_part1(EXAMPLE), Ok(4140.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day18::solve_part2(EXAMPLE), Ok(3993.into()));
+}
+
+#[test]
+fn magnitudes() {
+    for (number, magnitude) in [
+        ("[[1,2],[[3,4],5]]", 143),
+        ("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", 1384),
+        ("[[[[1,1],[2,2]],[3,3]],[4,4]]", 445),
+        ("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]", 3488),
+    ] {
+        assert_eq!(Day18::solve_part1(number), Ok(magnitude.into()), "{}", number);
+    }
+}
+
+#[test]
+fn explode_and_split() {
+    let sum = "[[[[4,3],4],4],[7,[[8,4],9]]]\n[1,1]\n";
+    // reduces to [[[[0,7],4],[[7,8],[6,0]]],[8,1]]
+    assert_eq!(Day18::solve_part1(sum), Ok(1384.into()));
+}
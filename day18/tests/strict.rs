@@ -0,0 +1,22 @@
+use common::{Error, ParseError, Solution};
+use day18::Day18;
+
+#[test]
+fn deeply_nested_pairs() {
+    common::parse::set_strict(true);
+    let number = format!("{}1{}", "[".repeat(100_000), ",1]".repeat(100_000));
+    assert_eq!(
+        Day18::parse(&number).err(),
+        Some(Error::Parse(ParseError::new(1, 5, "a number, pairs nest at most 4 deep", "["))),
+    );
+
+    // Pairs nested in four others would explode before any addition.
+    assert_eq!(
+        Day18::parse("[[[[[[1,2],3],4],5],6],7]").err(),
+        Some(Error::Parse(ParseError::new(1, 5, "a number, pairs nest at most 4 deep", "["))),
+    );
+    // The deepest homework still adds up.
+    let homework = "[[[[1,2],3],4],5]\n[[[[6,7],8],9],1]\n";
+    assert!(Day18::solve_part1(homework).is_ok());
+    assert!(Day18::solve_part2(homework).is_ok());
+}
@@ -0,0 +1,160 @@
+use common::{Answer, Result, Solution, Source};
+use geometry::{Rotation, Vec3};
+use itertools::Itertools;
+use rayon::prelude::*;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet, VecDeque};
+use tracing::{debug_span, Span};
+
+/// A beacon as seen by its scanner, turned every way the scanner could be facing.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "Vec3", into = "Vec3"))]
+pub struct Point {
+    orientations: [Vec3; 24],
+}
+
+impl Point {
+    fn new(x: i64, y: i64, z: i64) -> Self {
+        Vec3::new(x, y, z).into()
+    }
+}
+
+impl From<Vec3> for Point {
+    fn from(point: Vec3) -> Self {
+        Self { orientations: Rotation::all().map(|rotation| rotation * point) }
+    }
+}
+
+impl From<Point> for Vec3 {
+    /// The beacon as the input has it, since the identity is the first rotation.
+    fn from(point: Point) -> Self {
+        point.orientations[0]
+    }
+}
+
+/// The orientation and position of `unknown` relative to scanner 0, if at least 12 of its
+/// beacons line up with the ones of `known`, which is at `known_position`.
+fn align(known: &[Vec3], known_position: Vec3, unknown: &[Point]) -> Option<(usize, Vec3)> {
+    (0..24).into_par_iter().find_map_first(|orientation| {
+        let distances = known
+            .par_iter()
+            .map(|&known_coordinates| {
+                let mut distances = HashMap::<_, usize>::new();
+                for unknown_point in unknown {
+                    let unknown_coordinates = unknown_point.orientations[orientation];
+                    *distances.entry(known_coordinates - unknown_coordinates).or_default() += 1;
+                }
+                distances
+            })
+            .reduce(HashMap::new, |mut acc, distances: HashMap<Vec3, usize>| {
+                for (coordinates, matches) in distances.into_iter() {
+                    *acc.entry(coordinates).or_default() += matches
+                }
+                acc
+            });
+
+        // Ties go to the smallest distance rather than to whichever the hash map yields first.
+        let (&distance, &k) =
+            distances.iter().max_by_key(|&(&distance, &matches)| (matches, Reverse(distance)))?;
+        if k >= 12 {
+            Some((orientation, known_position + distance))
+        } else {
+            None
+        }
+    })
+}
+
+/// Aligns every scanner it can onto scanner 0, returning the beacons seen by them and the
+/// scanner positions, both relative to scanner 0.
+///
+/// Scanners are compared in parallel but aligned in order, so the result does not depend on how
+/// many threads there are nor on which finishes first.
+fn locate(scanners: &[Vec<Point>]) -> Result<(HashSet<Vec3>, Vec<Vec3>)> {
+    let mut known_scanner_to_test = VecDeque::new();
+    known_scanner_to_test.push_back(0);
+    let mut unknown_scanners = (1..scanners.len()).collect_vec();
+    let mut orientations = vec![None; scanners.len()];
+    let mut positions = vec![None; scanners.len()];
+    orientations[0] = Some(0);
+    positions[0] = Some(Vec3::ZERO);
+    let mut discovered = HashSet::<_>::from_iter(scanners[0].iter().map(|p| p.orientations[0]));
+
+    while let Some(known_scanner) = known_scanner_to_test.pop_front() {
+        common::cancel::check()?;
+        let (orientation, position) = (orientations[known_scanner], positions[known_scanner]);
+        let (orientation, position) = (orientation.unwrap(), position.unwrap());
+        // Relative to the known scanner, but facing the way scanner 0 does.
+        let known =
+            scanners[known_scanner].iter().map(|p| p.orientations[orientation]).collect_vec();
+
+        // Rayon's threads do not know which span the scanners are compared in.
+        let parent = Span::current();
+        let aligned = unknown_scanners
+            .par_iter()
+            .map(|&unknown_scanner| {
+                let _span =
+                    debug_span!(parent: &parent, "align", known_scanner, unknown_scanner).entered();
+                align(&known, position, &scanners[unknown_scanner])
+            })
+            .collect::<Vec<_>>();
+
+        for (unknown_scanner, aligned) in unknown_scanners.clone().into_iter().zip(aligned) {
+            let Some((orientation, position)) = aligned else {
+                continue;
+            };
+            unknown_scanners.retain(|&k| k != unknown_scanner);
+            orientations[unknown_scanner] = Some(orientation);
+            positions[unknown_scanner] = Some(position);
+            known_scanner_to_test.push_back(unknown_scanner);
+
+            for point in scanners[unknown_scanner].iter() {
+                discovered.insert(point.orientations[orientation] + position);
+            }
+        }
+    }
+
+    Ok((discovered, positions.into_iter().flatten().collect()))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Model = Vec<Vec<Point>>;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let source = Source::new(input);
+        let mut scanners: Vec<Vec<Point>> = vec![];
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            if line.starts_with("---") {
+                scanners.push(vec![]);
+                continue;
+            }
+            let scanner =
+                scanners.last_mut().ok_or_else(|| source.error(line, "a scanner header"))?;
+            let [x, y, z] = source.integers(line)?;
+            scanner.push(Point::new(x, y, z));
+        }
+
+        if scanners.is_empty() {
+            return Err(source.end("a scanner header").into());
+        }
+        Ok(scanners)
+    }
+
+    fn part1(scanners: &Self::Model) -> Result<Answer> {
+        let (discovered, _) = locate(scanners)?;
+        Ok(discovered.len().into())
+    }
+
+    fn part2(scanners: &Self::Model) -> Result<Answer> {
+        let (_, positions) = locate(scanners)?;
+        let distance = positions
+            .iter()
+            .tuple_combinations()
+            .map(|(position_1, position_2)| position_1.manhattan(*position_2))
+            .max()
+            .unwrap_or(0);
+        Ok(distance.into())
+    }
+}
@@ -0,0 +1,15 @@
+use common::Solution;
+use day19::Day19;
+
+// three scanners placed and rotated at random around a known beacon cloud
+const GENERATED: &str = include_str!("generated.txt");
+
+#[test]
+fn part1_generated() {
+    assert_eq!(Day19::solve_part1(GENERATED), Ok(120.into()));
+}
+
+#[test]
+fn part2_generated() {
+    assert_eq!(Day19::solve_part2(GENERATED), Ok(4109.into()));
+}
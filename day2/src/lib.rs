@@ -0,0 +1,160 @@
+pub mod trajectory;
+
+use common::{Answer, Output, ParseError, Result, Solution, Source};
+use std::str::FromStr;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Action {
+    Forward(i32),
+    Up(i32),
+    Down(i32),
+}
+
+impl FromStr for Action {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let source = Source::new(s);
+        let (direction, value) = source.split_once(s, " ")?;
+        let value: i32 = source.number(value)?;
+        match direction {
+            "forward" => Ok(Self::Forward(value)),
+            "up" => Ok(Self::Up(value)),
+            "down" => Ok(Self::Down(value)),
+            _ => Err(source.error(direction, "forward, up or down")),
+        }
+    }
+}
+
+/// How the submarine reads the planned course. Each part of day 2 has its own.
+pub trait SteeringModel {
+    fn steer(&mut self, action: &Action);
+    /// How far forward the submarine went.
+    fn position(&self) -> i32;
+    fn depth(&self) -> i32;
+
+    /// Where the submarine is heading, for the models that steer with an aim.
+    fn aim(&self) -> Option<i32> {
+        None
+    }
+}
+
+/// Up and down change the depth, as in part 1.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Direct {
+    pub position: i32,
+    pub depth: i32,
+}
+
+impl SteeringModel for Direct {
+    fn steer(&mut self, action: &Action) {
+        match action {
+            Action::Forward(value) => self.position += value,
+            Action::Up(value) => self.depth -= value,
+            Action::Down(value) => self.depth += value,
+        }
+    }
+
+    fn position(&self) -> i32 {
+        self.position
+    }
+
+    fn depth(&self) -> i32 {
+        self.depth
+    }
+}
+
+/// Up and down turn the submarine, which dives as it goes forward, as in part 2.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Aim {
+    pub position: i32,
+    pub depth: i32,
+    pub aim: i32,
+}
+
+impl SteeringModel for Aim {
+    fn steer(&mut self, action: &Action) {
+        match action {
+            Action::Forward(value) => {
+                self.position += value;
+                self.depth += self.aim * value;
+            }
+            Action::Up(value) => self.aim -= value,
+            Action::Down(value) => self.aim += value,
+        }
+    }
+
+    fn position(&self) -> i32 {
+        self.position
+    }
+
+    fn depth(&self) -> i32 {
+        self.depth
+    }
+
+    fn aim(&self) -> Option<i32> {
+        Some(self.aim)
+    }
+}
+
+/// Follows the whole course, answering with the product of where the submarine ends up.
+pub fn follow<'a, M: SteeringModel + ?Sized>(
+    model: &mut M,
+    actions: impl IntoIterator<Item = &'a Action>,
+) -> Output {
+    for action in actions {
+        model.steer(action);
+    }
+    output(model.position(), model.depth())
+}
+
+/// The answer for a submarine that ends up at `position` and `depth`.
+fn output(position: i32, depth: i32) -> Output {
+    Output::from(depth * position).with("depth", depth).with("position", position)
+}
+
+/// A steering model that can be picked by name.
+pub struct Steering {
+    pub name: &'static str,
+    pub new: fn() -> Box<dyn SteeringModel>,
+}
+
+/// The models of the puzzle. Tools that pick models by name add their own after these, as `aoc
+/// steer` does.
+pub const STEERING: &[Steering] = &[
+    Steering { name: "direct", new: || Box::<Direct>::default() },
+    Steering { name: "aim", new: || Box::<Aim>::default() },
+];
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Model = Vec<Action>;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let source = Source::new(input);
+        Ok(source.records(input.lines(), |line| {
+            Action::from_str(line).map_err(|e| source.locate(line, e))
+        })?)
+    }
+
+    fn part1(actions: &Self::Model) -> Result<Answer> {
+        Self::part1_output(actions).map(|output| output.answer)
+    }
+
+    fn part2(actions: &Self::Model) -> Result<Answer> {
+        Self::part2_output(actions).map(|output| output.answer)
+    }
+
+    fn part1_output(actions: &Self::Model) -> Result<Output> {
+        let output = follow(&mut Direct::default(), common::cancel::checked(actions));
+        common::cancel::check()?;
+        Ok(output)
+    }
+
+    fn part2_output(actions: &Self::Model) -> Result<Output> {
+        let output = follow(&mut Aim::default(), common::cancel::checked(actions));
+        common::cancel::check()?;
+        Ok(output)
+    }
+}
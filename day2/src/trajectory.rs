@@ -0,0 +1,106 @@
+//! Where the submarine is after each action of the course, to review a planned course as a table
+//! or as a depth profile.
+
+use crate::{output, Action, SteeringModel};
+use common::Output;
+use std::io::{self, Write};
+
+/// The submarine after `step` actions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct State {
+    pub step: usize,
+    pub position: i32,
+    pub depth: i32,
+    pub aim: Option<i32>,
+}
+
+impl State {
+    /// The answer [`follow`](crate::follow) gives for a course ending in this state.
+    pub fn output(&self) -> Output {
+        output(self.position, self.depth)
+    }
+}
+
+fn state<M: SteeringModel + ?Sized>(step: usize, model: &M) -> State {
+    State { step, position: model.position(), depth: model.depth(), aim: model.aim() }
+}
+
+/// Follows the whole course like [`follow`](crate::follow), keeping every state from the start.
+pub fn record<M: SteeringModel + ?Sized>(model: &mut M, actions: &[Action]) -> Vec<State> {
+    let mut trajectory = vec![state(0, model)];
+    for (step, action) in actions.iter().enumerate() {
+        model.steer(action);
+        trajectory.push(state(step + 1, model));
+    }
+    trajectory
+}
+
+/// Writes one line per state, leaving the aim out for the models without one.
+pub fn write_csv(trajectory: &[State], mut writer: impl Write) -> io::Result<()> {
+    writeln!(writer, "step,position,depth,aim")?;
+    for state in trajectory {
+        let aim = state.aim.map_or_else(String::new, |aim| aim.to_string());
+        writeln!(writer, "{},{},{},{}", state.step, state.position, state.depth, aim)?;
+    }
+    Ok(())
+}
+
+const WIDTH: f64 = 800.0;
+const HEIGHT: f64 = 400.0;
+/// Room around the plot for the labels.
+const MARGIN: f64 = 40.0;
+
+/// Draws the depth profile: the position along the x-axis and the depth going down the y-axis,
+/// with the surface and the furthest position and depth marked.
+pub fn write_svg(trajectory: &[State], mut writer: impl Write) -> io::Result<()> {
+    let range = |values: &mut dyn Iterator<Item = i32>| {
+        values.fold((0, 0), |(min, max), value| (min.min(value), max.max(value)))
+    };
+    let (min_position, max_position) = range(&mut trajectory.iter().map(|state| state.position));
+    let (min_depth, max_depth) = range(&mut trajectory.iter().map(|state| state.depth));
+    let scale = |value: i32, min: i32, max: i32, length: f64| {
+        let (value, min, max) = (f64::from(value), f64::from(min), f64::from(max));
+        MARGIN + (value - min) * (length - 2.0 * MARGIN) / (max - min).max(1.0)
+    };
+    let x = |position| scale(position, min_position, max_position, WIDTH);
+    let y = |depth| scale(depth, min_depth, max_depth, HEIGHT);
+
+    writeln!(
+        writer,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" font-family="sans-serif" font-size="12">"#,
+        WIDTH, HEIGHT
+    )?;
+    writeln!(writer, r#"<rect width="100%" height="100%" fill="white"/>"#)?;
+    writeln!(
+        writer,
+        r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="lightblue"/>"#,
+        x(min_position),
+        y(0),
+        x(max_position),
+        y(0)
+    )?;
+    writeln!(
+        writer,
+        r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="grey"/>"#,
+        x(0),
+        y(min_depth),
+        x(0),
+        y(max_depth)
+    )?;
+    for (label, anchor, x, y) in [
+        (format!("position {}", max_position), "end", x(max_position), y(0) - 6.0),
+        (format!("depth {}", max_depth), "start", x(0) + 6.0, y(max_depth)),
+    ] {
+        writeln!(writer, r#"<text x="{}" y="{}" text-anchor="{}">{}</text>"#, x, y, anchor, label)?;
+    }
+    let points = trajectory
+        .iter()
+        .map(|state| format!("{:.1},{:.1}", x(state.position), y(state.depth)))
+        .collect::<Vec<_>>();
+    writeln!(
+        writer,
+        r#"<polyline points="{}" fill="none" stroke="steelblue" stroke-width="2"/>"#,
+        points.join(" ")
+    )?;
+    writeln!(writer, "</svg>")
+}
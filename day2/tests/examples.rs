@@ -0,0 +1,36 @@
+use common::{Output, ParseError, Solution};
+use day2::{Action, Day2};
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day2::solve_part1(EXAMPLE), Ok(150.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day2::solve_part2(EXAMPLE), Ok(900.into()));
+}
+
+#[test]
+fn malformed_action() {
+    assert_eq!("down x".parse::<Action>().err(), Some(ParseError::new(1, 6, "a number", "x")));
+    assert_eq!(
+        "sideways 3".parse::<Action>().err(),
+        Some(ParseError::new(1, 1, "forward, up or down", "sideways"))
+    );
+}
+
+#[test]
+fn intermediate_results() {
+    let model = Day2::parse(EXAMPLE).unwrap();
+    assert_eq!(
+        Day2::part1_output(&model),
+        Ok(Output::from(150).with("depth", 10).with("position", 15))
+    );
+    assert_eq!(
+        Day2::part2_output(&model),
+        Ok(Output::from(900).with("depth", 60).with("position", 15))
+    );
+}
@@ -0,0 +1,55 @@
+use common::{Output, Solution};
+use day2::{follow, Action, Aim, Day2, SteeringModel, STEERING};
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+/// Aim, in a hull that can neither rise above the surface nor dive deeper than `max_depth`.
+struct Hull {
+    model: Aim,
+    max_depth: i32,
+}
+
+impl SteeringModel for Hull {
+    fn steer(&mut self, action: &Action) {
+        self.model.steer(action);
+        self.model.depth = self.model.depth.clamp(0, self.max_depth);
+    }
+
+    fn position(&self) -> i32 {
+        self.model.position
+    }
+
+    fn depth(&self) -> i32 {
+        self.model.depth
+    }
+
+    fn aim(&self) -> Option<i32> {
+        Some(self.model.aim)
+    }
+}
+
+#[test]
+fn models_by_name_solve_the_parts() {
+    let actions = Day2::read(EXAMPLE).unwrap();
+    let answers = STEERING
+        .iter()
+        .map(|steering| (steering.name, follow((steering.new)().as_mut(), &actions).answer))
+        .collect::<Vec<_>>();
+    assert_eq!(answers, [("direct", 150.into()), ("aim", 900.into())]);
+}
+
+#[test]
+fn custom_models_plug_in() {
+    let actions = Day2::read(EXAMPLE).unwrap();
+    let mut hull = Hull { model: Aim::default(), max_depth: 40 };
+    assert_eq!(
+        follow(&mut hull, &actions),
+        Output::from(600).with("depth", 40).with("position", 15)
+    );
+    assert_eq!(hull.model.depth, 40);
+
+    // Heading up, the submarine stays at the surface and dives again from there.
+    let actions = Day2::read("up 5\nforward 3\ndown 10\nforward 1\n").unwrap();
+    let mut hull = Hull { model: Aim::default(), max_depth: 40 };
+    assert_eq!(follow(&mut hull, &actions).answer, 20.into());
+}
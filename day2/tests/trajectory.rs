@@ -0,0 +1,64 @@
+use common::Solution;
+use day2::trajectory::{self, State};
+use day2::{Aim, Day2, Direct};
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn every_state_is_recorded() {
+    let actions = Day2::read(EXAMPLE).unwrap();
+    let trajectory = trajectory::record(&mut Aim::default(), &actions);
+    assert_eq!(trajectory.len(), actions.len() + 1);
+    assert_eq!(trajectory[0], State { step: 0, position: 0, depth: 0, aim: Some(0) });
+    assert_eq!(trajectory[6], State { step: 6, position: 15, depth: 60, aim: Some(10) });
+
+    let mut csv = vec![];
+    trajectory::write_csv(&trajectory[..3], &mut csv).unwrap();
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "step,position,depth,aim\n0,0,0,0\n1,5,0,0\n2,5,0,5\n"
+    );
+}
+
+#[test]
+fn the_last_state_gives_the_answer() {
+    let actions = Day2::read(EXAMPLE).unwrap();
+    let trajectory = trajectory::record(&mut Aim::default(), &actions);
+    let output = trajectory.last().unwrap().output();
+    assert_eq!(output, day2::follow(&mut Aim::default(), &actions));
+    assert_eq!(output.answer, 900.into());
+}
+
+#[test]
+fn models_without_aim_leave_it_out() {
+    let actions = Day2::read(EXAMPLE).unwrap();
+    let trajectory = trajectory::record(&mut Direct::default(), &actions);
+    assert!(trajectory.iter().all(|state| state.aim.is_none()));
+
+    let mut csv = vec![];
+    trajectory::write_csv(&trajectory[6..], &mut csv).unwrap();
+    assert_eq!(String::from_utf8(csv).unwrap(), "step,position,depth,aim\n6,15,10,\n");
+}
+
+#[test]
+fn profile_goes_right_and_down() {
+    let actions = Day2::read(EXAMPLE).unwrap();
+    let trajectory = trajectory::record(&mut Aim::default(), &actions);
+    let mut svg = vec![];
+    trajectory::write_svg(&trajectory, &mut svg).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    assert!(svg.starts_with("<svg ") && svg.ends_with("</svg>\n"));
+
+    let points = svg.split("points=\"").nth(1).unwrap().split('"').next().unwrap();
+    let points = points
+        .split(' ')
+        .map(|point| {
+            let (x, y) = point.split_once(',').unwrap();
+            (x.parse::<f64>().unwrap(), y.parse::<f64>().unwrap())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(points.len(), trajectory.len());
+    // The start is at the top left, the deepest and furthest point at the bottom right.
+    assert_eq!(points[0], (40.0, 40.0));
+    assert_eq!(points[6], (760.0, 360.0));
+}
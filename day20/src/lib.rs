@@ -0,0 +1,149 @@
+use common::{input, Answer, Result, Solution, Source};
+use grid::{Frame, Grid, Pixel};
+use rayon::prelude::*;
+use tracing::debug_span;
+
+#[inline]
+fn zone(input: &Grid<u8>, x: isize, y: isize, default: u8) -> usize {
+    (-1..=1)
+        .flat_map(|dy| (-1..=1).map(move |dx| *input.get_or(x + dx, y + dy, &default)))
+        .fold(0, |acc, bit| (acc << 1) + bit as usize)
+}
+
+/// Pixels outside of the image are all the same, but they may toggle at each step.
+fn next_default(default: u8, enhancement: &[u8]) -> u8 {
+    if default == 1 {
+        enhancement[(1 << 9) - 1]
+    } else {
+        enhancement[0]
+    }
+}
+
+fn enhance_image(image: &Grid<u8>, enhancement: &[u8], default: u8) -> Grid<u8> {
+    let _span = debug_span!("enhance", width = image.width(), height = image.height()).entered();
+    let width = image.width() + 2;
+    let cells = (0..width * (image.height() + 2))
+        .into_par_iter()
+        .map(|i| {
+            let (x, y) = ((i % width) as isize - 1, (i / width) as isize - 1);
+            enhancement[zone(image, x, y, default)]
+        })
+        .collect();
+    Grid::from_vec(width, cells).expect("the cells fill whole rows")
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trench {
+    enhancement: Vec<u8>,
+    image: Grid<u8>,
+}
+
+impl Trench {
+    /// The image after each enhancement, starting with the original one.
+    fn images(self) -> impl Iterator<Item = Grid<u8>> {
+        let Trench { enhancement, image } = self;
+        let mut default = 0;
+        std::iter::successors(Some(image), move |image| {
+            let new_image = enhance_image(image, &enhancement, default);
+            default = next_default(default, &enhancement);
+            Some(new_image)
+        })
+    }
+
+    fn lit_after(&self, steps: usize) -> Result<usize> {
+        let mut images = self.clone().images();
+        for _ in 0..steps {
+            common::cancel::check()?;
+            images.next();
+        }
+        let image = images.next().expect("images never run out");
+        Ok(image.cells().map(|&v| v as usize).sum::<usize>())
+    }
+}
+
+fn to_pixel(&bit: &u8) -> Pixel {
+    if bit == 1 {
+        Pixel::new('#', [255, 255, 255])
+    } else {
+        Pixel::new('.', [20, 20, 40])
+    }
+}
+
+fn pixel(c: char) -> Option<u8> {
+    match c {
+        '#' => Some(1),
+        '.' => Some(0),
+        _ => None,
+    }
+}
+
+pub struct Day20;
+
+impl Day20 {
+    /// The original image then each of the 50 enhancements of part 2.
+    pub fn frames(trench: &Trench) -> impl Iterator<Item = Frame> {
+        trench.clone().images().take(51).map(|image| image.map(to_pixel))
+    }
+}
+
+impl Solution for Day20 {
+    type Model = Trench;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let source = Source::new(input);
+        let mut sections = input::sections(input);
+        let algorithm = sections.next().ok_or_else(|| source.end("an enhancement algorithm"))?;
+        let image = sections.next().ok_or_else(|| source.missing(algorithm, "an image"))?;
+        if let Some(extra) = sections.next() {
+            return Err(source.unexpected(extra, "the end of the input").into());
+        }
+
+        let mut pixels = algorithm.char_indices().filter(|(_, c)| !c.is_whitespace());
+        let enhancement = pixels
+            .by_ref()
+            .take(1 << 9)
+            .map(|(i, c)| pixel(c).ok_or_else(|| source.unexpected(&algorithm[i..], "# or .")))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        if let Some((i, _)) = pixels.next() {
+            return Err(source
+                .unexpected(&algorithm[i..], "the end of the enhancement algorithm")
+                .into());
+        }
+        if enhancement.len() != 1 << 9 {
+            return Err(source.missing(algorithm, "512 pixels").into());
+        }
+
+        let image = Grid::parse_section(source, image, "# or .", pixel)?;
+
+        Ok(Trench { enhancement, image })
+    }
+
+    fn part1(trench: &Self::Model) -> Result<Answer> {
+        Ok(trench.lit_after(2)?.into())
+    }
+
+    fn part2(trench: &Self::Model) -> Result<Answer> {
+        Ok(trench.lit_after(50)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn background_toggles() {
+        let mut enhancement = vec![0; 1 << 9];
+        enhancement[0] = 1;
+        assert_eq!(next_default(0, &enhancement), 1);
+        assert_eq!(next_default(1, &enhancement), 0);
+    }
+
+    #[test]
+    fn background_stays_dark() {
+        let mut enhancement = vec![1; 1 << 9];
+        enhancement[0] = 0;
+        assert_eq!(next_default(0, &enhancement), 0);
+    }
+}
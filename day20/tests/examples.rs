@@ -0,0 +1,27 @@
+use common::Solution;
+use day20::Day20;
+
+// random images and algorithms, checked against a straightforward simulation
+const GENERATED: &str = include_str!("generated.txt");
+// `#` at index 0 and `.` at index 511: the infinite background flips at each step
+const TOGGLING: &str = include_str!("toggling.txt");
+
+#[test]
+fn part1_generated() {
+    assert_eq!(Day20::solve_part1(GENERATED), Ok(35.into()));
+}
+
+#[test]
+fn part2_generated() {
+    assert_eq!(Day20::solve_part2(GENERATED), Ok(2585.into()));
+}
+
+#[test]
+fn part1_toggling_background() {
+    assert_eq!(Day20::solve_part1(TOGGLING), Ok(31.into()));
+}
+
+#[test]
+fn part2_toggling_background() {
+    assert_eq!(Day20::solve_part2(TOGGLING), Ok(3259.into()));
+}
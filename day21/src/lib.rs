@@ -0,0 +1,240 @@
+use common::{Answer, Error, Result, Solution, Source};
+use num::bigint::BigInt;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+#[derive(Debug)]
+struct Dice {
+    value: usize,
+    rolls: usize,
+}
+
+impl Dice {
+    fn new() -> Self {
+        Self { value: 99, rolls: 0 }
+    }
+    fn roll(&mut self) -> usize {
+        self.value = (self.value + 1) % 100;
+        self.rolls += 1;
+        self.value + 1
+    }
+}
+
+#[derive(Debug)]
+struct Player {
+    position: usize,
+    score: usize,
+}
+
+impl Player {
+    fn step(&mut self, dice: &mut Dice) {
+        let action = (0..=2).fold(0, |acc, _| acc + dice.roll());
+        self.position = (self.position - 1 + action) % 10 + 1;
+        self.score += self.position
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Step {
+    player_1_position: u8,
+    player_2_position: u8,
+    player_1_score: u8,
+    player_2_score: u8,
+    next_player: u8,
+    previous: Option<Box<Step>>,
+    factor: BigInt,
+}
+
+impl Hash for Step {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (
+            self.player_1_position,
+            self.player_2_position,
+            self.player_1_score,
+            self.player_2_score,
+            self.next_player,
+        )
+            .hash(state)
+    }
+}
+
+impl PartialEq<Self> for Step {
+    fn eq(&self, other: &Self) -> bool {
+        (
+            self.player_1_position,
+            self.player_2_position,
+            self.player_1_score,
+            self.player_2_score,
+            self.next_player,
+        )
+            .eq(&(
+                other.player_1_position,
+                other.player_2_position,
+                other.player_1_score,
+                other.player_2_score,
+                other.next_player,
+            ))
+    }
+}
+
+impl Eq for Step {}
+
+enum StepResult {
+    Win(Step, u8),
+    Next(Vec<Step>),
+}
+
+const GOAL: u8 = 21;
+
+impl Step {
+    fn next(self: Step) -> StepResult {
+        if self.player_1_score >= GOAL || self.player_2_score >= GOAL {
+            StepResult::Win(self.clone(), (self.next_player + 1) % 2)
+        } else {
+            let next_steps = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)]
+                .into_iter()
+                .map(|(increment, occurrences)| {
+                    if self.next_player == 0 {
+                        let position = (self.player_1_position + increment - 1) % 10 + 1;
+                        Step {
+                            player_1_position: position,
+                            player_2_position: self.player_2_position,
+                            player_1_score: self.player_1_score + position,
+                            player_2_score: self.player_2_score,
+                            next_player: 1,
+                            previous: Some(Box::new(self.clone())),
+                            factor: BigInt::from(occurrences),
+                        }
+                    } else {
+                        let position = (self.player_2_position + increment - 1) % 10 + 1;
+                        Step {
+                            player_1_position: self.player_1_position,
+                            player_2_position: position,
+                            player_1_score: self.player_1_score,
+                            player_2_score: self.player_2_score + position,
+                            next_player: 0,
+                            previous: Some(Box::new(self.clone())),
+                            factor: BigInt::from(occurrences),
+                        }
+                    }
+                })
+                .collect();
+
+            StepResult::Next(next_steps)
+        }
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    /// Starting positions of both players.
+    type Model = (u8, u8);
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let source = Source::new(input);
+        let players = source.records(input.lines(), |line| {
+            let (_, position) = source.split_once(line, "starting position: ")?;
+            match source.number(position)? {
+                position @ 1..=10 => Ok((line, position)),
+                _ => Err(source.error(position, "a position from 1 to 10")),
+            }
+        })?;
+
+        match players[..] {
+            [(_, player_1), (_, player_2)] => Ok((player_1, player_2)),
+            [_, _, (extra, _), ..] => Err(source.error(extra, "end of input").into()),
+            _ => Err(source.end("a starting position").into()),
+        }
+    }
+
+    fn part1(&(player_1, player_2): &Self::Model) -> Result<Answer> {
+        let mut players =
+            [player_1, player_2].map(|position| Player { position: position as usize, score: 0 });
+
+        let mut dice = Dice::new();
+        'main: loop {
+            common::cancel::check()?;
+            for p in players.iter_mut() {
+                p.step(&mut dice);
+                if p.score >= 1000 {
+                    break 'main;
+                }
+            }
+        }
+        let loser = players.into_iter().find(|p| p.score < 1000).unwrap();
+        Ok((loser.score * dice.rolls).into())
+    }
+
+    fn part2(&(player_1, player_2): &Self::Model) -> Result<Answer> {
+        let start = Step {
+            player_1_position: player_1,
+            player_2_position: player_2,
+            player_1_score: 0,
+            player_2_score: 0,
+            next_player: 0,
+            previous: None,
+            factor: BigInt::from(1),
+        };
+
+        let mut knownsteps = HashMap::<Step, Vec<(Step, BigInt)>>::new();
+        let mut knownoccurrences = HashMap::<Step, BigInt>::new();
+        knownoccurrences.insert(start.clone(), BigInt::from(1));
+        let mut wins = HashMap::new();
+        let mut steps = vec![start];
+
+        while let Some(step) = steps.pop() {
+            common::cancel::check()?;
+            if let Some(occurrences) = knownsteps.get_mut(&step) {
+                occurrences.push((*step.previous.unwrap(), step.factor));
+                continue;
+            }
+            if let Some(previous) = &step.previous {
+                knownsteps.insert(step.clone(), vec![(*previous.clone(), step.factor.clone())]);
+            }
+
+            match step.next() {
+                StepResult::Next(next_steps) => steps.extend(next_steps),
+
+                StepResult::Win(step, player) => {
+                    wins.insert(step, player);
+                }
+            }
+        }
+
+        let (mut wins_1, mut wins_2) = (BigInt::from(0), BigInt::from(0));
+
+        while !knownsteps.is_empty() {
+            common::cancel::check()?;
+            let mut remove = vec![];
+            for (step, previous) in knownsteps.iter() {
+                if previous.iter().all(|(step, _)| knownoccurrences.contains_key(step)) {
+                    let occurrences: BigInt = previous
+                        .iter()
+                        .map(|(step, occurrences)| {
+                            knownoccurrences.get(step).unwrap().clone() * occurrences
+                        })
+                        .sum();
+                    knownoccurrences.insert(step.clone(), occurrences.clone());
+                    remove.push(step.clone());
+
+                    if let Some(&player) = wins.get(step) {
+                        if player == 0 {
+                            wins_1 += occurrences;
+                        } else {
+                            wins_2 += occurrences;
+                        }
+                    }
+                }
+            }
+
+            for step in remove.drain(..) {
+                knownsteps.remove(&step);
+            }
+        }
+
+        let wins = u64::try_from(wins_1.max(wins_2))
+            .map_err(|_| Error::NoSolution("too many universes".to_string()))?;
+        Ok(wins.into())
+    }
+}
@@ -0,0 +1,14 @@
+use common::Solution;
+use day21::Day21;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day21::solve_part1(EXAMPLE), Ok(739785.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day21::solve_part2(EXAMPLE), Ok(444356092776315i64.into()));
+}
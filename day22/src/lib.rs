@@ -0,0 +1,166 @@
+use common::{Answer, ParseError, Result, Solution, Source};
+use geometry::{Cuboid, Vec3};
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+#[derive(Debug)]
+enum Direction {
+    X,
+    Y,
+    Z,
+}
+
+#[derive(Debug)]
+struct Step {
+    direction: Direction,
+    range: RangeInclusive<i64>,
+}
+
+impl Step {
+    fn parse<'a>(source: Source<'a>, s: &'a str) -> std::result::Result<Self, ParseError> {
+        let (direction, range) = source.split_once(s, "=")?;
+        let direction = match direction {
+            "x" => Direction::X,
+            "y" => Direction::Y,
+            "z" => Direction::Z,
+            _ => return Err(source.error(direction, "x, y or z")),
+        };
+        let (from, to) = source.split_once(range, "..")?;
+        let (from, to) = (source.number(from)?, source.number(to)?);
+        if from > to {
+            return Err(source.error(range, "an increasing range"));
+        }
+
+        Ok(Self { direction, range: (from..=to) })
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Instruction {
+    on: bool,
+    volume: Cuboid,
+}
+
+impl Instruction {
+    fn parse<'a>(source: Source<'a>, s: &'a str) -> std::result::Result<Self, ParseError> {
+        let (switch, steps) = source.split_once(s, " ")?;
+        let on = match switch {
+            "on" => true,
+            "off" => false,
+            _ => return Err(source.error(switch, "on or off")),
+        };
+
+        let mut range_x = None;
+        let mut range_y = None;
+        let mut range_z = None;
+
+        for step in steps.split(',') {
+            let step = Step::parse(source, step)?;
+            match step.direction {
+                Direction::X => range_x = Some(step.range),
+                Direction::Y => range_y = Some(step.range),
+                Direction::Z => range_z = Some(step.range),
+            }
+        }
+
+        let x = range_x.ok_or_else(|| source.missing(s, "an x range"))?;
+        let y = range_y.ok_or_else(|| source.missing(s, "a y range"))?;
+        let z = range_z.ok_or_else(|| source.missing(s, "a z range"))?;
+        Ok(Self {
+            on,
+            volume: Cuboid::new(
+                Vec3::new(*x.start(), *y.start(), *z.start()),
+                Vec3::new(*x.end(), *y.end(), *z.end()),
+            ),
+        })
+    }
+}
+
+/// Splits `volume` along the faces of `cut`, so each part is either fully inside or fully outside.
+fn cut(volume: Cuboid, cut: &Cuboid) -> Vec<Cuboid> {
+    let mut parts = vec![volume];
+    for axis in 0..3 {
+        for at in [cut.min[axis], cut.max[axis] + 1] {
+            parts = parts
+                .into_iter()
+                .flat_map(|part| {
+                    let (below, above) = part.split(axis, at);
+                    below.into_iter().chain(above)
+                })
+                .collect();
+        }
+    }
+    parts
+}
+
+/// How many cubes are on after the instructions, switching them one by one.
+pub fn count_cubes<'a>(instructions: impl IntoIterator<Item = &'a Instruction>) -> usize {
+    let mut grid = HashSet::new();
+    for Instruction { on, volume } in instructions {
+        for x in volume.min.x..=volume.max.x {
+            for y in volume.min.y..=volume.max.y {
+                for z in volume.min.z..=volume.max.z {
+                    if *on {
+                        grid.insert((x, y, z));
+                    } else {
+                        grid.remove(&(x, y, z));
+                    }
+                }
+            }
+        }
+    }
+    grid.len()
+}
+
+/// Like `count_cubes`, keeping the cubes that are on as disjoint volumes.
+pub fn count_volumes<'a>(instructions: impl IntoIterator<Item = &'a Instruction>) -> Result<usize> {
+    let mut grid: Vec<Cuboid> = vec![];
+
+    for instruction in instructions {
+        common::cancel::check()?;
+        let mut next_grid = vec![];
+        for volume in grid {
+            if volume.intersects(&instruction.volume) {
+                next_grid.extend(
+                    cut(volume, &instruction.volume)
+                        .into_iter()
+                        .filter(|part| !part.intersects(&instruction.volume)),
+                );
+            } else {
+                next_grid.push(volume);
+            }
+        }
+
+        if instruction.on {
+            next_grid.push(instruction.volume);
+        }
+        grid = next_grid;
+    }
+
+    Ok(grid.iter().map(|volume| volume.volume() as usize).sum::<usize>())
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Model = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let source = Source::new(input);
+        Ok(source.records(input.lines(), |line| Instruction::parse(source, line))?)
+    }
+
+    fn part1(instructions: &Self::Model) -> Result<Answer> {
+        let region = Cuboid::new(Vec3::new(-50, -50, -50), Vec3::new(50, 50, 50));
+        let initialization =
+            instructions.iter().filter(|instruction| region.encloses(&instruction.volume));
+        let cubes = count_cubes(common::cancel::checked(initialization));
+        common::cancel::check()?;
+        Ok(cubes.into())
+    }
+
+    fn part2(instructions: &Self::Model) -> Result<Answer> {
+        Ok(count_volumes(instructions)?.into())
+    }
+}
@@ -0,0 +1,26 @@
+use common::Solution;
+use day22::{count_cubes, count_volumes, Day22};
+use proptest::prelude::*;
+
+/// Overlapping cuboids in a small region, switched on or off.
+fn reboot() -> impl Strategy<Value = String> {
+    let range = (-8..8i64, 0..6i64).prop_map(|(from, length)| (from, from + length));
+    let step = (any::<bool>(), range.clone(), range.clone(), range);
+    prop::collection::vec(step, 0..10).prop_map(|steps| {
+        steps
+            .into_iter()
+            .map(|(on, (x1, x2), (y1, y2), (z1, z2))| {
+                let switch = if on { "on" } else { "off" };
+                format!("{} x={}..{},y={}..{},z={}..{}\n", switch, x1, x2, y1, y2, z1, z2)
+            })
+            .collect()
+    })
+}
+
+proptest! {
+    #[test]
+    fn splitting_volumes_agrees_with_switching_cubes(input in reboot()) {
+        let instructions = Day22::parse(&input).unwrap();
+        prop_assert_eq!(count_volumes(&instructions).unwrap(), count_cubes(&instructions));
+    }
+}
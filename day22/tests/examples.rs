@@ -0,0 +1,21 @@
+use common::Solution;
+use day22::Day22;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day22::solve_part1(EXAMPLE), Ok(39.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day22::solve_part2(EXAMPLE), Ok(39.into()));
+}
+
+#[test]
+fn part1_ignores_cuboids_outside_the_initialization_area() {
+    let steps = "on x=-60..-51,y=0..0,z=0..0\non x=49..50,y=0..0,z=0..0\n";
+    assert_eq!(Day22::solve_part1(steps), Ok(2.into()));
+    assert_eq!(Day22::solve_part2(steps), Ok(12.into()));
+}
@@ -0,0 +1,347 @@
+use common::{Answer, Error, Output, ParseError, Result, Solution, Source};
+use grid::frame::{self, Frame};
+use search::{Checkpoint, State};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Amphipod {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl Amphipod {
+    /// Column of the side room of the amphipod.
+    fn room(&self) -> usize {
+        match self {
+            Amphipod::A => 3,
+            Amphipod::B => 5,
+            Amphipod::C => 7,
+            Amphipod::D => 9,
+        }
+    }
+
+    fn cost(&self) -> usize {
+        match self {
+            Amphipod::A => 1,
+            Amphipod::B => 10,
+            Amphipod::C => 100,
+            Amphipod::D => 1000,
+        }
+    }
+}
+
+impl TryFrom<char> for Amphipod {
+    type Error = ();
+
+    fn try_from(c: char) -> std::result::Result<Self, Self::Error> {
+        match c {
+            'A' => Ok(Self::A),
+            'B' => Ok(Self::B),
+            'C' => Ok(Self::C),
+            'D' => Ok(Self::D),
+            _ => Err(()),
+        }
+    }
+}
+
+struct Rules {
+    destinations: HashMap<usize, Amphipod>,
+    /// Row of the bottom of the side rooms.
+    bottom: usize,
+}
+
+type Path = Vec<HashMap<(usize, usize), Amphipod>>;
+
+/// The amphipods of the diagram by position.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Burrow(
+    #[cfg_attr(feature = "serde", serde(with = "common::pairs"))] HashMap<(usize, usize), Amphipod>,
+);
+
+#[derive(Clone)]
+struct Game<'a> {
+    rules: &'a Rules,
+    amphipods: HashMap<(usize, usize), Amphipod>,
+}
+
+/// Draws the burrow as in the puzzle, with side rooms going down to row `bottom`.
+fn draw(amphipods: &HashMap<(usize, usize), Amphipod>, bottom: usize) -> String {
+    let mut template = String::from("#############\n#...........#\n###.#.#.#.###\n");
+    for _ in 3..=bottom {
+        template.push_str("  #.#.#.#.#  \n");
+    }
+    template.push_str("  #########");
+    let mut template = template.chars().collect::<Vec<_>>();
+    for ((x, y), amphipod) in amphipods {
+        template[y * 14 + x] = format!("{:?}", amphipod).chars().next().unwrap();
+    }
+    String::from_iter(template)
+}
+
+impl Display for Game<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&draw(&self.amphipods, self.rules.bottom), f)
+    }
+}
+
+impl<'a> State for Game<'a> {
+    type Key = Vec<((usize, usize), Amphipod)>;
+    type Cost = usize;
+
+    fn key(&self) -> Self::Key {
+        let mut key =
+            self.amphipods.iter().map(|(&position, &a)| (position, a)).collect::<Vec<_>>();
+        key.sort_unstable();
+        key
+    }
+
+    fn successors(&self) -> Vec<(Self, usize)> {
+        self.clone().next().collect()
+    }
+
+    fn is_goal(&self) -> bool {
+        self.amphipods.iter().all(|(&(x, _), amphipod)| x == amphipod.room())
+    }
+
+    /// Every amphipod outside of its room has to go up to the hallway, along it, then down into
+    /// the room.
+    fn heuristic(&self) -> usize {
+        self.amphipods
+            .iter()
+            .filter(|(&(x, _), &amphipod)| x != amphipod.room())
+            .map(|(&(x, y), amphipod)| (y - 1 + x.abs_diff(amphipod.room()) + 1) * amphipod.cost())
+            .sum()
+    }
+}
+
+impl Checkpoint for Game<'_> {
+    type Saved = Vec<((usize, usize), Amphipod)>;
+
+    fn save(&self) -> Self::Saved {
+        self.key()
+    }
+
+    fn restore(&self, saved: Self::Saved) -> Self {
+        Self { rules: self.rules, amphipods: saved.into_iter().collect() }
+    }
+
+    fn describe(&self) -> String {
+        let home = self.amphipods.iter().filter(|(&(x, _), amphipod)| x == amphipod.room());
+        format!("{} of {} amphipods in their room", home.count(), self.amphipods.len())
+    }
+}
+
+impl<'a> Game<'a> {
+    /// The games one move away, with the energy the move takes.
+    fn next(self) -> impl Iterator<Item = (Game<'a>, usize)> + 'a {
+        self.amphipods
+            .clone()
+            .into_iter()
+            .filter_map(move |((x, y), amphipod)| {
+                if y == self.rules.bottom && self.rules.destinations.get(&x) == Some(&amphipod) {
+                    None
+                } else {
+                    let mut next_games = vec![];
+                    let mut new_game = self.clone();
+                    new_game.amphipods.remove(&(x, y));
+
+                    let mut add = |dx: usize, dy: usize| {
+                        let mut next_game = new_game.clone();
+                        let moves = dx.max(x) - dx.min(x) + dy.max(y) - dy.min(y);
+                        next_game.amphipods.insert((dx, dy), amphipod);
+                        next_games.push((next_game, moves * amphipod.cost()))
+                    };
+
+                    new_game.to_hallway_moves(x, y, amphipod, &mut add);
+                    new_game.to_room_moves(x, y, amphipod, &mut add);
+
+                    Some(next_games)
+                }
+            })
+            .flatten()
+    }
+
+    fn to_hallway_moves(
+        &self,
+        x: usize,
+        y: usize,
+        amphipod: Amphipod,
+        f: &mut impl FnMut(usize, usize),
+    ) {
+        if y == 1
+            || (y > 2 && (2..y).any(|y| self.amphipods.contains_key(&(x, y))))
+            || (self.rules.destinations.get(&x) == Some(&amphipod)
+                && (y + 1..=self.rules.bottom)
+                    .all(|y| self.amphipods.get(&(x, y)) == Some(&amphipod)))
+        {
+            return;
+        }
+
+        for direction in [(1..=x - 1).rev().collect::<Vec<usize>>(), (x + 1..=11).collect()] {
+            for dest_x in direction {
+                if [3, 5, 7, 9].contains(&dest_x) {
+                    continue;
+                }
+                if self.amphipods.contains_key(&(dest_x, 1)) {
+                    break;
+                }
+                f(dest_x, 1)
+            }
+        }
+    }
+
+    fn to_room_moves(
+        &self,
+        x: usize,
+        y: usize,
+        amphipod: Amphipod,
+        f: &mut impl FnMut(usize, usize),
+    ) {
+        if y >= 2 {
+            return;
+        }
+
+        for direction in [(x + 1..=9).collect::<Vec<usize>>(), (3..=x - 1).rev().collect()] {
+            for dest_x in direction {
+                if self.amphipods.contains_key(&(dest_x, 1)) {
+                    break;
+                }
+
+                if self.rules.destinations.get(&dest_x) == Some(&amphipod)
+                    && ((3..=self.rules.bottom).all(|y| {
+                        !self.amphipods.contains_key(&(dest_x, y))
+                            || self.amphipods[&(dest_x, y)] == amphipod
+                    }))
+                {
+                    if let Some(dest_y) = (2..=self.rules.bottom)
+                        .rev()
+                        .find(|y| !self.amphipods.contains_key(&(dest_x, *y)))
+                    {
+                        f(dest_x, dest_y)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Lowest energy needed to organize the amphipods, whose rooms go down to row `bottom`, and the
+/// positions they go through on the way.
+fn organize(amphipods: HashMap<(usize, usize), Amphipod>, bottom: usize) -> Result<(Output, Path)> {
+    let rules = Rules {
+        destinations: HashMap::from([
+            (3, Amphipod::A),
+            (5, Amphipod::B),
+            (7, Amphipod::C),
+            (9, Amphipod::D),
+        ]),
+        bottom,
+    };
+
+    let name = format!("day23-depth{}", bottom - 1);
+    let start = Game { rules: &rules, amphipods };
+    let outcome = search::checkpoint::run(&name, &(start.key(), bottom), start)?;
+    let found = outcome
+        .found
+        .ok_or_else(|| Error::NoSolution("amphipods cannot be organized".to_string()))?;
+    let output = Output::from(found.cost)
+        .with("moves", found.path.len() - 1)
+        .with("expanded", outcome.stats.expanded);
+    Ok((output, found.path.into_iter().map(|game| game.amphipods).collect()))
+}
+
+fn colour(c: char) -> frame::Rgb {
+    match c {
+        'A' => [230, 80, 80],
+        'B' => [240, 200, 60],
+        'C' => [80, 200, 120],
+        'D' => [90, 140, 240],
+        '#' => [120, 120, 120],
+        _ => [40, 40, 40],
+    }
+}
+
+pub struct Day23;
+
+impl Day23 {
+    /// The burrow of part 1 after each move of the cheapest way to organize the amphipods.
+    pub fn frames(Burrow(amphipods): &Burrow) -> Result<impl Iterator<Item = Frame>> {
+        let (_, path) = organize(amphipods.clone(), 3)?;
+        Ok(path.into_iter().map(|amphipods| frame::from_text(&draw(&amphipods, 3), colour)))
+    }
+}
+
+impl Solution for Day23 {
+    type Model = Burrow;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let source = Source::new(input);
+        let mut amphipods = HashMap::new();
+        for (y, line) in input.lines().enumerate() {
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                match Amphipod::try_from(c) {
+                    Ok(amphipod) => {
+                        amphipods.insert((x, y), amphipod);
+                    }
+                    Err(()) if "#. ".contains(c) => {}
+                    Err(()) => {
+                        return Err(source.unexpected(&line[i..], "an amphipod or #").into())
+                    }
+                }
+            }
+        }
+
+        let rooms = [3, 5, 7, 9].iter().flat_map(|&x| [(x, 2), (x, 3)]);
+        if let Some((x, y)) = rooms.clone().find(|room| !amphipods.contains_key(room)) {
+            let found = input.lines().nth(y).and_then(|line| line.chars().nth(x));
+            let found = found.map(String::from).unwrap_or_default();
+            return Err(ParseError::new(y + 1, x + 1, "an amphipod", found).into());
+        }
+        if let Some(&(x, y)) =
+            amphipods.keys().find(|position| !rooms.clone().any(|room| room == **position))
+        {
+            let found = format!("{:?}", amphipods[&(x, y)]);
+            return Err(ParseError::new(y + 1, x + 1, "an amphipod in a side room", found).into());
+        }
+        Ok(Burrow(amphipods))
+    }
+
+    fn part1(amphipods: &Self::Model) -> Result<Answer> {
+        Self::part1_output(amphipods).map(|output| output.answer)
+    }
+
+    fn part2(amphipods: &Self::Model) -> Result<Answer> {
+        Self::part2_output(amphipods).map(|output| output.answer)
+    }
+
+    fn part1_output(Burrow(amphipods): &Self::Model) -> Result<Output> {
+        Ok(organize(amphipods.clone(), 3)?.0)
+    }
+
+    fn part2_output(Burrow(amphipods): &Self::Model) -> Result<Output> {
+        let mut amphipods = amphipods.clone();
+        for x in [3, 5, 7, 9] {
+            amphipods.insert((x, 5), amphipods[&(x, 3)]);
+        }
+
+        // the folded part of the diagram
+        for (x, y, amphipod) in [
+            (3, 3, Amphipod::D),
+            (3, 4, Amphipod::D),
+            (5, 3, Amphipod::C),
+            (5, 4, Amphipod::B),
+            (7, 3, Amphipod::B),
+            (7, 4, Amphipod::A),
+            (9, 3, Amphipod::A),
+            (9, 4, Amphipod::C),
+        ] {
+            amphipods.insert((x, y), amphipod);
+        }
+
+        Ok(organize(amphipods, 5)?.0)
+    }
+}
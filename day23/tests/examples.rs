@@ -0,0 +1,14 @@
+use common::Solution;
+use day23::Day23;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day23::solve_part1(EXAMPLE), Ok(12521.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day23::solve_part2(EXAMPLE), Ok(44169.into()));
+}
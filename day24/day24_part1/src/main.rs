@@ -0,0 +1,8 @@
+use common::Solution;
+use day24::Day24;
+use std::io;
+
+fn main() -> common::Result<()> {
+    println!("{}", Day24::solve_part1(&io::read_to_string(io::stdin()).unwrap())?);
+    Ok(())
+}
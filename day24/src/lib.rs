@@ -0,0 +1,1457 @@
+#![cfg_attr(feature = "pedantic", warn(clippy::pedantic))]
+#![warn(clippy::use_self)]
+#![warn(clippy::map_flatten)]
+#![warn(clippy::map_unwrap_or)]
+#![warn(deprecated_in_future)]
+#![warn(future_incompatible)]
+#![warn(noop_method_call)]
+#![warn(missing_debug_implementations)]
+#![warn(rust_2018_compatibility)]
+#![warn(rust_2021_compatibility)]
+#![warn(rust_2018_idioms)]
+#![warn(unused)]
+#![deny(warnings)]
+
+use common::{Answer, Error, Output, ParseError, Solution, Source};
+use itertools::Itertools;
+use lru::LruCache;
+use search::{Checkpoint, State};
+use std::cell::{Cell, Ref, RefCell, RefMut};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+use std::str::FromStr;
+use tracing::{debug, debug_span, trace_span};
+
+#[derive(Hash, Eq, PartialEq, Copy, Clone, Debug, serde::Serialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+enum Variable {
+    X,
+    Y,
+    Z,
+    W,
+}
+
+impl Display for Variable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!("{:?}", self))
+    }
+}
+
+impl FromStr for Variable {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let result = match s {
+            "x" => Self::X,
+            "y" => Self::Y,
+            "z" => Self::Z,
+            "w" => Self::W,
+            _ => return Err(()),
+        };
+        Ok(result)
+    }
+}
+
+#[derive(Debug, Hash, serde::Serialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+enum VariableOrNumber {
+    Variable(Variable),
+    Number(i64),
+}
+
+#[derive(Debug, Clone)]
+struct ValueRef {
+    rc_value: Rc<ValueContainer>,
+}
+
+impl PartialOrd<Self> for ValueRef {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Eq for ValueRef {}
+
+impl Ord for ValueRef {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let op1 = OP_ORDER.iter().position(|o| self.op_str() == *o).unwrap();
+        let op2 = OP_ORDER.iter().position(|o| other.op_str() == *o).unwrap();
+
+        op1.cmp(&op2).then_with(|| match (&*self.ref_value(), &*other.ref_value()) {
+            (Value::Number(n1), Value::Number(n2)) => n1.cmp(n2),
+            (Value::Input(n1), Value::Input(n2)) => n1.cmp(n2),
+            _ => self
+                .children()
+                .cmp(&other.children())
+                .then_with(|| self.equalities().cmp(&other.equalities())),
+        })
+    }
+}
+
+impl PartialEq for ValueRef {
+    fn eq(&self, other: &Self) -> bool {
+        let res = match (&*self.ref_value(), &*other.ref_value()) {
+            (Value::Number(a), Value::Number(b)) => a == b,
+            (Value::Input(a), Value::Input(b)) => a == b,
+            _ => Rc::ptr_eq(&self.rc_value, &other.rc_value),
+        };
+        res && self.rc_value.solved.borrow().0 == other.rc_value.solved.borrow().0
+    }
+}
+
+impl Hash for ValueRef {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::ptr::hash(self.op_str(), state);
+        self.rc_value.solved.borrow().0.hash(state);
+        for child in self.children() {
+            std::ptr::hash(Rc::as_ptr(&child.rc_value), state);
+        }
+
+        match &*self.ref_value() {
+            Value::Number(n) => n.hash(state),
+            Value::Input(n) => n.hash(state),
+            _ => {}
+        }
+    }
+}
+
+const OP_ORDER: [&str; 7] = ["Number", "Input", "Add", "Mul", "Div", "Mod", "Eql"];
+
+#[derive(Debug)]
+struct ValueContainer {
+    inner: RefCell<Value>,
+    solved: RefCell<Equalities>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Ord, PartialOrd, Hash)]
+enum Value {
+    Number(i64),
+    Input(u8),
+    Add(ValueRef, ValueRef),
+    Mul(ValueRef, ValueRef),
+    Div(ValueRef, ValueRef),
+    Mod(ValueRef, ValueRef),
+    Eql(ValueRef, ValueRef, Option<u8>),
+}
+
+impl Display for ValueRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let out = match &*self.ref_value() {
+            Value::Number(n) => n.to_string(),
+            Value::Input(n) => {
+                format!("Input({})", n)
+            }
+            Value::Add(a, b)
+            | Value::Mul(a, b)
+            | Value::Div(a, b)
+            | Value::Mod(a, b)
+            | Value::Eql(a, b, _) => {
+                format!("{}({}, {})", self.op_str(), a, b)
+            }
+        };
+        f.write_str(&out)
+    }
+}
+
+/// An LRU cache that counts how many lookups find what they look for.
+struct Cache<K, V> {
+    name: &'static str,
+    lru: RefCell<LruCache<K, V>>,
+    hits: Cell<u64>,
+    misses: Cell<u64>,
+}
+
+impl<K: Hash + Eq, V> Cache<K, V> {
+    fn new(name: &'static str, capacity: usize) -> Self {
+        Self {
+            name,
+            lru: RefCell::new(LruCache::new(capacity)),
+            hits: Cell::new(0),
+            misses: Cell::new(0),
+        }
+    }
+
+    /// What `find` finds in the cache, counted as a hit or a miss.
+    fn lookup<T>(&self, find: impl FnOnce(&mut LruCache<K, V>) -> Option<T>) -> Option<T> {
+        let _span = trace_span!("lookup", cache = self.name).entered();
+        let found = find(&mut self.lru.borrow_mut());
+        let counter = if found.is_some() { &self.hits } else { &self.misses };
+        counter.set(counter.get() + 1);
+        found
+    }
+
+    fn borrow_mut(&self) -> RefMut<'_, LruCache<K, V>> {
+        self.lru.borrow_mut()
+    }
+
+    fn trace(&self) {
+        let (hits, misses) = (self.hits.get(), self.misses.get());
+        debug!(cache = self.name, hits, misses, "lru lookups");
+    }
+}
+
+type ResolutionCache = Cache<ValueRef, HashMap<[Option<i64>; 14], ValueRef>>;
+
+struct Factory {
+    cache: Cache<ValueRef, ValueRef>,
+    input_dependencies: Cache<ValueRef, [bool; 14]>,
+    inputs_resolves: ResolutionCache,
+    deductions: Cache<ValueRef, Deduction>,
+}
+
+impl Factory {
+    fn new() -> Self {
+        Self {
+            cache: Cache::new("cache", 1000),
+            input_dependencies: Cache::new("input_dependencies", 1000),
+            inputs_resolves: Cache::new("inputs_resolves", 1000),
+            deductions: Cache::new("deductions", 1000),
+        }
+    }
+
+    fn get_cached(&self, value: &ValueRef) -> Option<ValueRef> {
+        self.cache.lookup(|cache| cache.get(value).cloned())
+    }
+
+    fn set_deduction(&self, value_ref: &ValueRef) -> Deduction {
+        if let Some(deduction) = self.deductions.lookup(|cache| cache.get(value_ref).cloned()) {
+            return deduction;
+        }
+
+        let deduction = match &*value_ref.ref_value() {
+            Value::Number(n) => Deduction::In { min: *n, max: *n },
+            Value::Input(_) => Deduction::In { min: 1, max: 9 },
+            _ => {
+                let children = value_ref.children();
+                let deduction_a = self.set_deduction(&children[0]);
+                let deduction_b = self.set_deduction(&children[1]);
+
+                if deduction_a == Deduction::Impossible || deduction_b == Deduction::Impossible {
+                    Deduction::Impossible
+                } else if let (
+                    Deduction::In { min: min_a, max: max_a },
+                    Deduction::In { min: mut min_b, max: mut max_b },
+                ) = (deduction_a, deduction_b)
+                {
+                    match &*value_ref.ref_value() {
+                        Value::Add(_, _) => Deduction::In {
+                            min: min_a.saturating_add(min_b),
+                            max: max_a.saturating_add(max_b),
+                        },
+                        Value::Mul(_, _) => {
+                            if min_a == max_a || min_b == max_b {
+                                Deduction::In {
+                                    min: min_b
+                                        .saturating_mul(min_a)
+                                        .min(max_b.saturating_mul(max_a)),
+                                    max: min_b
+                                        .saturating_mul(min_a)
+                                        .max(max_b.saturating_mul(max_a)),
+                                }
+                            } else {
+                                let mut results = [
+                                    min_a.saturating_mul(min_b),
+                                    min_a.saturating_mul(max_b),
+                                    max_a.saturating_mul(min_b),
+                                    max_a.saturating_mul(max_b),
+                                ];
+                                results.sort_unstable();
+                                Deduction::In { min: results[0], max: results[3] }
+                            }
+                        }
+                        Value::Div(_, _) => {
+                            if max_a < min_b {
+                                Deduction::In { min: 0, max: 0 }
+                            } else {
+                                if min_b < max_b {
+                                    if min_b == 0 {
+                                        min_b += 1
+                                    } else if max_b == 0 {
+                                        max_b -= 1
+                                    }
+                                }
+                                let results = [
+                                    min_a.checked_div(min_b),
+                                    min_a.checked_div(max_b),
+                                    max_a.checked_div(min_b),
+                                    max_a.checked_div(max_b),
+                                ];
+                                results.iter().flatten().minmax().into_option().map_or(
+                                    Deduction::Impossible,
+                                    |(&min, &max)| Deduction::In { min, max },
+                                )
+                            }
+                        }
+                        Value::Mod(_, _) => {
+                            if (min_a < 0 && max_a < 0) || (min_b <= 0 && max_b <= 0) {
+                                Deduction::Impossible
+                            } else if max_a < min_b {
+                                Deduction::In { min: min_a.max(0), max: max_a }
+                            } else {
+                                Deduction::In { min: 0, max: max_b.saturating_sub(1) }
+                            }
+                        }
+
+                        Value::Eql(_, _, _) => {
+                            if min_a == max_a && min_a == min_b && min_a == max_b {
+                                Deduction::In { min: 1, max: 1 }
+                            } else if max_a < min_b || min_a > max_b {
+                                Deduction::In { min: 0, max: 0 }
+                            } else {
+                                Deduction::In { min: 0, max: 1 }
+                            }
+                        }
+                        _ => unreachable!(),
+                    }
+                } else {
+                    Deduction::Unknown
+                }
+            }
+        };
+
+        self.deductions.borrow_mut().put(value_ref.clone(), deduction.clone());
+        deduction
+    }
+
+    fn set_input_dependencies(&self, value_ref: &ValueRef) -> [bool; 14] {
+        if let Some(dependencies) =
+            self.input_dependencies.lookup(|cache| cache.get(value_ref).copied())
+        {
+            return dependencies;
+        }
+
+        let ref_dependencies = {
+            match &*value_ref.ref_value() {
+                Value::Input(n) => {
+                    let mut inputs = [false; 14];
+                    inputs[*n as usize] = true;
+                    inputs
+                }
+                Value::Number(_) => [false; 14],
+                Value::Add(a, b)
+                | Value::Mul(a, b)
+                | Value::Div(a, b)
+                | Value::Mod(a, b)
+                | Value::Eql(a, b, _) => {
+                    let mut inputs = self.set_input_dependencies(a);
+                    let b_dependencies = self.set_input_dependencies(b);
+
+                    for (k, v) in b_dependencies.iter().enumerate() {
+                        inputs[k] |= *v
+                    }
+                    inputs
+                }
+            }
+        };
+        self.input_dependencies.borrow_mut().put(value_ref.clone(), ref_dependencies);
+        ref_dependencies
+    }
+
+    fn new_is_reducible(
+        &self,
+        value: Value,
+        solved: Option<Equalities>,
+    ) -> Result<(ValueRef, bool), &'static str> {
+        let solved = solved.unwrap_or(Equalities([None; 14]));
+
+        let mut result = ValueRef {
+            rc_value: Rc::new(ValueContainer {
+                inner: RefCell::new(value),
+                solved: RefCell::new(solved),
+            }),
+        };
+
+        if let Some(target_value) = self.get_cached(&result) {
+            return Ok((target_value, false));
+        }
+        let reduced = result.reduce(self)?;
+
+        if let Some(value) = self.get_cached(&result) {
+            return Ok((value, reduced));
+        }
+        self.set_input_dependencies(&result);
+        self.set_deduction(&result);
+        self.inputs_resolves.borrow_mut().put(result.clone(), HashMap::new());
+        self.cache.borrow_mut().put(result.clone(), result.clone());
+        Ok((result, reduced))
+    }
+
+    fn try_new(&self, value: Value, solved: Option<Equalities>) -> Result<ValueRef, &'static str> {
+        self.new_is_reducible(value, solved).map(|(value, _)| value)
+    }
+
+    fn trace(&self) {
+        self.cache.trace();
+        self.input_dependencies.trace();
+        self.inputs_resolves.trace();
+        self.deductions.trace();
+    }
+}
+
+impl ValueRef {
+    fn equalities(&self) -> Equalities {
+        self.rc_value.solved.borrow().clone()
+    }
+
+    fn op_str(&self) -> &'static str {
+        match &*self.ref_value() {
+            Value::Number(_) => "Number",
+            Value::Input(_) => "Input",
+            Value::Add(_, _) => "Add",
+            Value::Mul(_, _) => "Mul",
+            Value::Div(_, _) => "Div",
+            Value::Mod(_, _) => "Mod",
+            Value::Eql(_, _, _) => "Eql",
+        }
+    }
+    fn value_for_inputs(
+        &self,
+        inputs: &[Option<i64>; 14],
+        factory: &Factory,
+    ) -> Result<Self, &'static str> {
+        let mut key = *inputs;
+        let input_dependencies = factory.set_input_dependencies(self);
+
+        for (i, v) in input_dependencies.iter().enumerate() {
+            if !v {
+                key[i] = None
+            }
+        }
+
+        if let Some(value) = factory
+            .inputs_resolves
+            .lookup(|cache| cache.get(self).and_then(|e| e.get(&key)).cloned())
+        {
+            return Ok(value);
+        };
+
+        let value = self.solve(factory, &key)?;
+        {
+            // does not change a thing
+            let eq = { value.rc_value.solved.borrow().clone() };
+            let eq = eq.add(&value.equalities());
+            value.rc_value.solved.replace(eq);
+        }
+
+        let mut inputs_resolves = factory.inputs_resolves.borrow_mut();
+
+        let entry = if let Some(entry) = inputs_resolves.get_mut(self) {
+            entry
+        } else {
+            inputs_resolves.put(self.clone(), HashMap::new());
+            inputs_resolves.get_mut(self).unwrap()
+        };
+        entry.insert(key, value.clone());
+        Ok(value)
+    }
+
+    fn solve(&self, factory: &Factory, inputs: &[Option<i64>; 14]) -> Result<Self, &'static str> {
+        let (a, b, eql) = match &*self.ref_value() {
+            Value::Number(_) => return Ok(self.clone()),
+            Value::Input(n) => {
+                return if let Some(value) = inputs[*n as usize] {
+                    factory.try_new(Value::Number(value), None)
+                } else {
+                    Ok(self.clone())
+                };
+            }
+            Value::Add(a, b)
+            | Value::Mul(a, b)
+            | Value::Div(a, b)
+            | Value::Mod(a, b)
+            | Value::Eql(a, b, _) => {
+                let found = if let Value::Eql(_, _, Some(idx)) = &*self.ref_value() {
+                    Some(*idx)
+                } else {
+                    None
+                };
+                let new_a = a.value_for_inputs(inputs, factory)?;
+                let new_b = b.value_for_inputs(inputs, factory)?;
+                let equalities =
+                    self.equalities().add(&new_a.equalities()).add(&new_b.equalities()); // ...
+                let result = if let (&Value::Number(n_a), &Value::Number(n_b)) =
+                    (&*new_a.ref_value(), &*new_b.ref_value())
+                {
+                    (n_a, n_b, equalities)
+                } else {
+                    if &new_a == a && &new_b == b {
+                        return Ok(self.clone());
+                    }
+
+                    let new_value = match &*self.ref_value() {
+                        Value::Number(_) | Value::Input(_) => unreachable!(),
+                        Value::Add(_, _) => Value::Add(new_a.clone(), new_b.clone()),
+                        Value::Mul(_, _) => Value::Mul(new_a.clone(), new_b.clone()),
+                        Value::Div(_, _) => Value::Div(new_a.clone(), new_b.clone()),
+                        Value::Mod(_, _) => Value::Mod(new_a.clone(), new_b.clone()),
+                        Value::Eql(_, _, _) => Value::Eql(new_a.clone(), new_b.clone(), found),
+                    };
+
+                    return factory.try_new(new_value, Some(equalities));
+                };
+                result
+            }
+        };
+
+        let mut found = self.equalities().add(&eql);
+        let result = match &*self.ref_value() {
+            Value::Input(_) | Value::Number(_) => unreachable!(),
+            Value::Add(_, _) => a.wrapping_add(b),
+            Value::Mul(_, _) => a.wrapping_mul(b),
+            Value::Div(_, _) => a.checked_div(b).ok_or("Division by zero")?,
+            Value::Mod(_, _) => {
+                if a < 0 || b <= 0 {
+                    return Err("Invalid modulo");
+                }
+                a % b
+            }
+            Value::Eql(_, _, is_var) => {
+                if a == b {
+                    if let Some(v) = is_var {
+                        found.win(*v)
+                    }
+                    1
+                } else {
+                    if let Some(v) = is_var {
+                        found.fail(*v)
+                    }
+                    0
+                }
+            }
+        };
+        factory.try_new(Value::Number(result), Some(found))
+    }
+
+    fn ref_value(&self) -> Ref<'_, Value> {
+        RefCell::borrow(&self.rc_value.inner)
+    }
+
+    fn clone_value(&self) -> Value {
+        (*self.ref_value()).clone()
+    }
+
+    fn ref_mut_value(&self) -> RefMut<'_, Value> {
+        RefCell::borrow_mut(&self.rc_value.inner)
+    }
+
+    fn reorder<'a>(values: (&'a Self, &'a Self)) -> (&'a Self, &'a Self, bool) {
+        if values.0 > values.1 {
+            (values.1, values.0, true)
+        } else {
+            (values.0, values.1, false)
+        }
+    }
+
+    fn reorder_4(values: [&Self; 4]) -> ([&Self; 4], bool) {
+        let mut result = values;
+        result.sort();
+        (result, values != result)
+    }
+
+    fn reorder_3(values: [&Self; 3]) -> ([&Self; 3], bool) {
+        let mut result = values;
+        result.sort();
+        (result, values != result)
+    }
+
+    fn children(&self) -> Vec<Self> {
+        match &*self.ref_value() {
+            Value::Number(_) | Value::Input(_) => vec![],
+            Value::Add(a, b)
+            | Value::Mul(a, b)
+            | Value::Div(a, b)
+            | Value::Mod(a, b)
+            | Value::Eql(a, b, _) => vec![a.clone(), b.clone()],
+        }
+    }
+
+    fn reduce(&mut self, factory: &Factory) -> Result<bool, &'static str> {
+        let mut changed = false;
+        let mut next_value: Option<Value> = None;
+        let mut found = Equalities([None; 14]);
+
+        loop {
+            if next_value.is_some() {
+                let mut self_ref_mut = self.ref_mut_value();
+                let value = &mut *self_ref_mut;
+                changed = true;
+                let eq = {
+                    let eq = self.rc_value.solved.borrow().clone();
+                    eq.add(&found)
+                };
+                self.rc_value.solved.replace(eq);
+                *value = next_value.take().unwrap();
+            }
+
+            match &*self.ref_value() {
+                Value::Add(a, b) => {
+                    let (a, b, reordered) = Self::reorder((a, b));
+
+                    if reordered {
+                        next_value = Some(Value::Add(a.clone(), b.clone()));
+                        continue;
+                    }
+
+                    if *a.ref_value() == Value::Number(0) {
+                        next_value = Some(b.clone_value());
+                        continue;
+                    }
+
+                    if let (Value::Number(a), Value::Number(b)) = (&*a.ref_value(), &*b.ref_value())
+                    {
+                        next_value = Some(Value::Number(a.wrapping_add(*b)));
+                        continue;
+                    }
+
+                    if a == b {
+                        next_value = Some(Value::Mul(
+                            factory.try_new(
+                                Value::Number(2),
+                                Some(a.equalities().add(&b.equalities())),
+                            )?,
+                            a.clone(),
+                        ));
+                        continue;
+                    }
+
+                    if let (Value::Add(a1, a2), Value::Add(b1, b2)) =
+                        (&*a.ref_value(), &*b.ref_value())
+                    {
+                        if a1 == a2 && a1 == b1 && a1 == b2 {
+                            next_value = Some(Value::Mul(
+                                factory.try_new(
+                                    Value::Number(4),
+                                    Some(
+                                        a1.equalities()
+                                            .add(&b1.equalities())
+                                            .add(&a2.equalities())
+                                            .add(&b2.equalities()),
+                                    ),
+                                )?,
+                                a1.clone(),
+                            ));
+                            continue;
+                        }
+                        let ([a1, a2, b1, b2], _) = Self::reorder_4([a1, a2, b1, b2]);
+                        next_value = Some(Value::Add(
+                            a1.clone(),
+                            factory.try_new(
+                                Value::Add(
+                                    a2.clone(),
+                                    factory.try_new(
+                                        Value::Add(b1.clone(), b2.clone()),
+                                        Some(
+                                            a1.equalities()
+                                                .add(&b1.equalities())
+                                                .add(&a2.equalities())
+                                                .add(&b2.equalities()),
+                                        ),
+                                    )?,
+                                ),
+                                Some(a1.equalities()),
+                            )?,
+                        ));
+                        continue;
+                    }
+
+                    if let (a, Value::Add(b1, b2)) = (a, &*b.ref_value()) {
+                        if a == b1 && a == b2 {
+                            next_value = Some(Value::Mul(
+                                factory.try_new(
+                                    Value::Number(3),
+                                    Some(
+                                        a.equalities().add(&b1.equalities()).add(&b2.equalities()),
+                                    ),
+                                )?,
+                                a.clone(),
+                            ));
+                            continue;
+                        }
+
+                        let ([a, b1, b2], reordered) = Self::reorder_3([a, b1, b2]);
+                        changed = changed || reordered;
+
+                        let (reduced_ab1, is_reduced) = factory.new_is_reducible(
+                            Value::Add(a.clone(), b1.clone()),
+                            Some(a.equalities().add(&b1.equalities())),
+                        )?;
+                        if is_reduced {
+                            next_value = Some(Value::Add(reduced_ab1, b2.clone()));
+                            continue;
+                        }
+
+                        let (reduced_ab2, is_reduced) = factory.new_is_reducible(
+                            Value::Add(a.clone(), b2.clone()),
+                            Some(a.equalities().add(&b2.equalities())),
+                        )?;
+                        if is_reduced {
+                            next_value = Some(Value::Add(reduced_ab2, b1.clone()));
+                            continue;
+                        }
+
+                        if reordered {
+                            next_value = Some(Value::Add(
+                                a.clone(),
+                                factory.try_new(
+                                    Value::Add(b1.clone(), b2.clone()),
+                                    Some(b1.equalities().add(&b2.equalities())),
+                                )?,
+                            ));
+                            continue;
+                        }
+                    }
+
+                    if let (Value::Mul(a1, a2), Value::Mul(b1, b2)) =
+                        (&*a.ref_value(), &*b.ref_value())
+                    {
+                        if a1 == b1 {
+                            next_value = Some(Value::Mul(
+                                a1.clone(),
+                                factory.try_new(
+                                    Value::Add(a2.clone(), b2.clone()),
+                                    Some(a2.equalities().add(&b2.equalities())),
+                                )?,
+                            ));
+                            continue;
+                        }
+                        if a1 == b2 {
+                            next_value = Some(Value::Mul(
+                                a1.clone(),
+                                factory.try_new(
+                                    Value::Add(a2.clone(), b1.clone()),
+                                    Some(a2.equalities().add(&b1.equalities())),
+                                )?,
+                            ));
+                            continue;
+                        }
+                        if a2 == b1 {
+                            next_value = Some(Value::Mul(
+                                a2.clone(),
+                                factory.try_new(
+                                    Value::Add(a1.clone(), b2.clone()),
+                                    Some(a1.equalities().add(&b2.equalities())),
+                                )?,
+                            ));
+                            continue;
+                        }
+                        if a2 == b2 {
+                            next_value = Some(Value::Mul(
+                                a2.clone(),
+                                factory.try_new(
+                                    Value::Add(a1.clone(), b1.clone()),
+                                    Some(a1.equalities().add(&b1.equalities())),
+                                )?,
+                            ));
+                            continue;
+                        }
+                    }
+
+                    if let Value::Mul(b1, b2) = &*b.ref_value() {
+                        if a == b1 {
+                            next_value = Some(Value::Mul(
+                                a.clone(),
+                                factory.try_new(
+                                    Value::Add(
+                                        factory.try_new(Value::Number(1), None)?,
+                                        b2.clone(),
+                                    ),
+                                    Some(b2.equalities()),
+                                )?,
+                            ));
+                            continue;
+                        }
+                        if a == b2 {
+                            next_value = Some(Value::Mul(
+                                a.clone(),
+                                factory.try_new(
+                                    Value::Add(
+                                        factory.try_new(Value::Number(1), None)?,
+                                        b1.clone(),
+                                    ),
+                                    Some(b1.equalities()),
+                                )?,
+                            ));
+                            continue;
+                        }
+                    }
+                }
+                Value::Mul(a, b) => {
+                    let (a, b, reordered) = Self::reorder((a, b));
+
+                    if reordered {
+                        next_value = Some(Value::Mul(a.clone(), b.clone()));
+                        continue;
+                    }
+
+                    if *a.ref_value() == Value::Number(0) {
+                        next_value = Some(Value::Number(0));
+                        continue;
+                    }
+
+                    if let (Value::Number(a), Value::Number(b)) = (&*a.ref_value(), &*b.ref_value())
+                    {
+                        next_value = Some(Value::Number(a.wrapping_mul(*b)));
+                        continue;
+                    }
+
+                    if *a.ref_value() == Value::Number(1) {
+                        next_value = Some(b.ref_value().clone());
+                        continue;
+                    }
+
+                    if let (Value::Mul(a1, a2), Value::Mul(b1, b2)) =
+                        (&*a.ref_value(), &*b.ref_value())
+                    {
+                        let ([a1, a2, b1, b2], reordered) = Self::reorder_4([a1, a2, b1, b2]);
+
+                        if reordered {
+                            next_value = Some(Value::Mul(
+                                a1.clone(),
+                                factory.try_new(
+                                    Value::Mul(
+                                        a2.clone(),
+                                        factory.try_new(
+                                            Value::Mul(b1.clone(), b2.clone()),
+                                            Some(b1.equalities().add(&b2.equalities())),
+                                        )?,
+                                    ),
+                                    Some(a1.equalities()),
+                                )?,
+                            ));
+                            continue;
+                        }
+                    }
+
+                    if let (a, Value::Mul(b1, b2)) = (a, &*b.ref_value()) {
+                        let ([a, b1, b2], reordered) = Self::reorder_3([a, b1, b2]);
+
+                        if reordered {
+                            next_value = Some(Value::Mul(
+                                a.clone(),
+                                factory.try_new(
+                                    Value::Mul(b1.clone(), b2.clone()),
+                                    Some(b1.equalities().add(&b2.equalities())),
+                                )?,
+                            ));
+                            continue;
+                        }
+
+                        let (reduced_ab1, is_reduced) = factory.new_is_reducible(
+                            Value::Mul(a.clone(), b1.clone()),
+                            Some(a.equalities().add(&b1.equalities())),
+                        )?;
+                        if is_reduced {
+                            next_value = Some(Value::Mul(reduced_ab1, b2.clone()));
+                            continue;
+                        }
+
+                        let (reduced_ab2, is_reduced) = factory.new_is_reducible(
+                            Value::Mul(a.clone(), b2.clone()),
+                            Some(a.equalities().add(&b2.equalities())),
+                        )?;
+                        if is_reduced {
+                            next_value = Some(Value::Mul(reduced_ab2, b1.clone()));
+                            continue;
+                        }
+                    }
+
+                    if let (a, Value::Div(b1, b2)) = (a, &*b.ref_value()) {
+                        let (reduced_ab1, is_reduced) = factory.new_is_reducible(
+                            Value::Mul(a.clone(), b1.clone()),
+                            Some(a.equalities().add(&b1.equalities())),
+                        )?;
+                        if is_reduced {
+                            next_value = Some(Value::Div(reduced_ab1, b2.clone()));
+                            continue;
+                        }
+
+                        let (reduced_ab2, is_reduced) = factory.new_is_reducible(
+                            Value::Div(a.clone(), b2.clone()),
+                            Some(a.equalities().add(&b2.equalities())),
+                        )?;
+                        if is_reduced {
+                            next_value = Some(Value::Mul(reduced_ab2, b1.clone()));
+                            continue;
+                        }
+                    }
+                }
+                Value::Input(_) | Value::Number(_) => {}
+                Value::Div(a, b) => {
+                    if *b.ref_value() == Value::Number(0) {
+                        return Err("division by zero");
+                    }
+                    if *a.ref_value() == Value::Number(0) {
+                        next_value = Some(Value::Number(0));
+                        continue;
+                    }
+
+                    if *b.ref_value() == Value::Number(1) {
+                        next_value = Some(a.ref_value().clone());
+                        continue;
+                    }
+
+                    if let (Value::Number(a), Value::Number(b)) = (&*a.ref_value(), &*b.ref_value())
+                    {
+                        next_value =
+                            Some(Value::Number(a.checked_div(*b).ok_or("Division by zero")?));
+                        continue;
+                    }
+
+                    if let Value::Mul(a1, a2) = &*a.ref_value() {
+                        let (reduced_a1b, is_reduced) = factory.new_is_reducible(
+                            Value::Div(a1.clone(), b.clone()),
+                            Some(a1.equalities().add(&b.equalities())),
+                        )?;
+                        if is_reduced {
+                            next_value = Some(Value::Mul(reduced_a1b, a2.clone()));
+                            continue;
+                        }
+
+                        let (reduced_a2b, is_reduced) = factory.new_is_reducible(
+                            Value::Div(a2.clone(), b.clone()),
+                            Some(a2.equalities().add(&b.equalities())),
+                        )?;
+                        if is_reduced {
+                            next_value = Some(Value::Mul(reduced_a2b, a1.clone()));
+                            continue;
+                        }
+                    }
+                }
+                Value::Mod(a, b) => {
+                    if let Value::Number(b) = &*b.ref_value() {
+                        if *b <= 0 {
+                            return Err("modulo by b <= 0");
+                        }
+                        if let Value::Number(a) = &*a.ref_value() {
+                            next_value = Some(Value::Number(*a % *b));
+                            continue;
+                        }
+                    }
+
+                    if *a.ref_value() == Value::Number(0) {
+                        next_value = Some(Value::Number(0));
+                        continue;
+                    }
+                }
+                Value::Eql(a, b, found_) => {
+                    let (a, b, reordered) = Self::reorder((a, b));
+
+                    if reordered {
+                        next_value = Some(Value::Eql(a.clone(), b.clone(), *found_));
+                        continue;
+                    }
+
+                    if a == b {
+                        if let Some(idx) = found_ {
+                            found.win(*idx)
+                        }
+                        next_value = Some(Value::Number(1));
+                        continue;
+                    }
+
+                    if let (Value::Number(a), Value::Number(b)) = (&*a.ref_value(), &*b.ref_value())
+                    {
+                        if a != b {
+                            if let Some(idx) = found_ {
+                                found.fail(*idx)
+                            }
+                            next_value = Some(Value::Number(0));
+                            continue;
+                        }
+                    }
+
+                    if let (Value::Number(a), Value::Input(_)) = (&*a.ref_value(), &*b.ref_value())
+                    {
+                        if !(1..=9).contains(a) {
+                            if let Some(idx) = found_ {
+                                found.fail(*idx)
+                            }
+                            next_value = Some(Value::Number(0));
+                            continue;
+                        }
+                    }
+
+                    let (a, b, reordered) = Self::reorder((a, b));
+                    if reordered {
+                        next_value = Some(Value::Eql(a.clone(), b.clone(), *found_));
+                        continue;
+                    }
+                }
+            };
+
+            return Ok(changed);
+        }
+    }
+}
+
+impl FromStr for VariableOrNumber {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(variable) = s.parse() {
+            Ok(Self::Variable(variable))
+        } else {
+            Ok(Self::Number(s.parse().map_err(|_| ())?))
+        }
+    }
+}
+
+#[derive(Debug, Hash, serde::Serialize)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+enum Instruction {
+    Inp(Variable),
+    Add(Variable, VariableOrNumber),
+    Mul(Variable, VariableOrNumber),
+    Div(Variable, VariableOrNumber),
+    Mod(Variable, VariableOrNumber),
+    Eql(Variable, VariableOrNumber, bool),
+}
+
+/// Reads the next operand of an instruction, or reports it as `expected`.
+fn operand<'a, T: FromStr>(
+    source: Source<'a>,
+    parts: &mut impl Iterator<Item = &'a str>,
+    after: &'a str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    let part = parts.next().ok_or_else(|| source.missing(after, expected))?;
+    part.parse().map_err(|_| source.error(part, expected))
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let source = Source::new(s);
+        let mut parts = s.split(' ');
+        let operation = parts.next().unwrap_or(s);
+        let variable = "w, x, y or z";
+        let value = "w, x, y, z or a number";
+        let result = match operation {
+            "inp" => Self::Inp(operand(source, &mut parts, s, variable)?),
+            "add" => Self::Add(
+                operand(source, &mut parts, s, variable)?,
+                operand(source, &mut parts, s, value)?,
+            ),
+            "mul" => Self::Mul(
+                operand(source, &mut parts, s, variable)?,
+                operand(source, &mut parts, s, value)?,
+            ),
+            "div" => Self::Div(
+                operand(source, &mut parts, s, variable)?,
+                operand(source, &mut parts, s, value)?,
+            ),
+            "mod" => Self::Mod(
+                operand(source, &mut parts, s, variable)?,
+                operand(source, &mut parts, s, value)?,
+            ),
+            "eql" => {
+                let part1 = operand(source, &mut parts, s, variable)?;
+                let part2 = operand(source, &mut parts, s, value)?;
+                let is_var = match part2 {
+                    VariableOrNumber::Variable(_) => true,
+                    VariableOrNumber::Number(_) => false,
+                };
+                Self::Eql(part1, part2, is_var)
+            }
+            _ => return Err(source.error(operation, "inp, add, mul, div, mod or eql")),
+        };
+        match parts.next() {
+            Some(extra) => Err(source.error(extra, "end of line")),
+            None => Ok(result),
+        }
+    }
+}
+
+impl Instruction {
+    fn solve_placeholder(
+        placeholder: &VariableOrNumber,
+        variables: &mut HashMap<Variable, ValueRef>,
+        factory: &Factory,
+    ) -> Result<ValueRef, &'static str> {
+        match placeholder {
+            VariableOrNumber::Variable(name) => {
+                Ok(variables.get(name).ok_or("unknown variable")?.clone())
+            }
+            &VariableOrNumber::Number(n) => Ok(factory.try_new(Value::Number(n), None))?,
+        }
+    }
+
+    fn execute(
+        &self,
+        input_count: &mut u8,
+        eql_count: &mut u8,
+        variables: &mut HashMap<Variable, ValueRef>,
+        inputs: &[Option<i64>; 14],
+        factory: &Factory,
+    ) -> Result<(), &'static str> {
+        match self {
+            &Self::Inp(variable) => {
+                if let Some(value) =
+                    *inputs.get(*input_count as usize).ok_or("more than 14 inputs")?
+                {
+                    variables.insert(variable, factory.try_new(Value::Number(value), None)?);
+                } else {
+                    let mut result = factory.try_new(Value::Input(*input_count), None)?;
+                    result.reduce(factory)?;
+                    variables.insert(variable, result);
+                }
+
+                *input_count += 1;
+            }
+            Self::Add(variable, placeholder) => {
+                let value = Self::solve_placeholder(placeholder, variables, factory)?;
+
+                let mut result = factory.try_new(
+                    Value::Add(variables.get(variable).ok_or("unknown variable")?.clone(), value),
+                    None,
+                )?;
+                result.reduce(factory)?;
+                variables.insert(*variable, result);
+            }
+            Self::Mul(variable, placeholder) => {
+                let value = Self::solve_placeholder(placeholder, variables, factory)?;
+                let mut result = factory.try_new(
+                    Value::Mul(variables.get(variable).ok_or("unknown variable")?.clone(), value),
+                    None,
+                )?;
+                result.reduce(factory)?;
+                variables.insert(*variable, result);
+            }
+            Self::Div(variable, placeholder) => {
+                let value = Self::solve_placeholder(placeholder, variables, factory)?;
+                let mut result = factory.try_new(
+                    Value::Div(variables.get(variable).ok_or("unknown variable")?.clone(), value),
+                    None,
+                )?;
+                result.reduce(factory)?;
+                variables.insert(*variable, result);
+            }
+            Self::Mod(variable, placeholder) => {
+                let value = Self::solve_placeholder(placeholder, variables, factory)?;
+                let mut result = factory.try_new(
+                    Value::Mod(variables.get(variable).ok_or("unknown variable")?.clone(), value),
+                    None,
+                )?;
+                result.reduce(factory)?;
+                variables.insert(*variable, result);
+            }
+            Self::Eql(variable, placeholder, is_var) => {
+                let value = Self::solve_placeholder(placeholder, variables, factory)?;
+                if *is_var && *eql_count == 14 {
+                    return Err("more than 14 comparisons of variables");
+                }
+                let var = if *is_var { Some(*eql_count) } else { None };
+                let mut result = factory.try_new(
+                    Value::Eql(
+                        variables.get(variable).ok_or("unknown variable")?.clone(),
+                        value,
+                        var,
+                    ),
+                    None,
+                )?;
+                result.reduce(factory)?;
+                variables.insert(*variable, result);
+                if *is_var {
+                    *eql_count += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize)]
+enum Goal {
+    Largest,
+    Smallest,
+}
+
+impl Goal {
+    /// Digit used for inputs that are not decided yet: the best any completion can reach.
+    fn fill(self) -> i64 {
+        match self {
+            Self::Largest => 9,
+            Self::Smallest => 1,
+        }
+    }
+}
+
+fn inputs_to_string(inputs: &[Option<i64>; 14], fill: Option<i64>) -> String {
+    inputs
+        .iter()
+        .map(|x| match x.or(fill) {
+            Some(x) => x.to_string(),
+            None => " ".to_string(),
+        })
+        .collect_vec()
+        .join("")
+}
+
+#[derive(Clone, Debug)]
+struct Equalities([Option<bool>; 14]);
+
+impl PartialOrd for Equalities {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Eq for Equalities {}
+
+impl Ord for Equalities {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let self_score: (usize, usize) =
+            self.0.iter().flatten().fold((0, 0), |(wins, fails), v| {
+                if *v {
+                    (wins + 1, fails)
+                } else {
+                    (wins, fails + 1)
+                }
+            });
+        let other_score: (usize, usize) =
+            other.0.iter().flatten().fold((0, 0), |(wins, fails), v| {
+                if *v {
+                    (wins + 1, fails)
+                } else {
+                    (wins, fails + 1)
+                }
+            });
+        self_score.cmp(&other_score)
+    }
+}
+
+impl PartialEq for Equalities {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl Display for Equalities {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!("{:?}", self.0))
+    }
+}
+
+impl Equalities {
+    fn add(self, other: &Self) -> Self {
+        let mut result = self;
+        for (idx, v) in other.0.iter().enumerate() {
+            match v {
+                None => {}
+                Some(true) => {
+                    if result.0[idx] == Some(false) {
+                        unreachable!()
+                    }
+                    result.0[idx] = Some(true);
+                }
+                Some(false) => {
+                    if result.0[idx] == Some(true) {
+                        unreachable!()
+                    }
+                    result.0[idx] = Some(false);
+                }
+            }
+        }
+        result
+    }
+
+    fn win(&mut self, idx: u8) {
+        if self.0[idx as usize] == Some(false) {
+            unreachable!()
+        }
+        self.0[idx as usize] = Some(true)
+    }
+
+    fn fail(&mut self, idx: u8) {
+        if self.0[idx as usize] == Some(true) {
+            unreachable!()
+        }
+        self.0[idx as usize] = Some(false)
+    }
+}
+
+/// Some inputs of the model number decided, from the first, the others left to the best digit.
+#[derive(Clone)]
+struct Step<'a> {
+    z: &'a ValueRef,
+    factory: &'a Factory,
+    inputs: [Option<i64>; 14],
+    value: Option<i64>,
+    goal: Goal,
+}
+
+impl State for Step<'_> {
+    type Key = [Option<i64>; 14];
+    /// How far the model number is from the best one, the undecided inputs being their best.
+    type Cost = u64;
+
+    fn key(&self) -> Self::Key {
+        self.inputs
+    }
+
+    fn successors(&self) -> Vec<(Self, u64)> {
+        let Some(idx) = self.inputs.iter().position(Option::is_none) else {
+            return vec![];
+        };
+        // The ALU fails for every model number with these digits, dividing by zero say.
+        let Ok(result) = self.z.value_for_inputs(&self.inputs, self.factory) else {
+            return vec![];
+        };
+        let dependencies = self.factory.set_input_dependencies(&result);
+        // When `z` does not depend on the input, its best digit does as well as any other.
+        let fill = self.goal.fill();
+        let digits = if dependencies[idx] { 1..=9 } else { fill..=fill };
+
+        digits
+            .filter_map(|v| {
+                let mut inputs = self.inputs;
+                inputs[idx] = Some(v);
+                if let Ok(result) = self.z.solve(self.factory, &inputs) {
+                    if let Deduction::In { min, max } = self.factory.set_deduction(&result) {
+                        if !(min..=max).contains(&0) {
+                            return None;
+                        }
+                    }
+                }
+                let cost = (v - fill).unsigned_abs() * 10u64.pow(13 - idx as u32);
+                Some((Self::new(self.z, self.factory, inputs, self.goal), cost))
+            })
+            .collect()
+    }
+
+    fn is_goal(&self) -> bool {
+        self.value == Some(0)
+    }
+}
+
+impl Checkpoint for Step<'_> {
+    type Saved = [Option<i64>; 14];
+
+    fn save(&self) -> Self::Saved {
+        self.inputs
+    }
+
+    fn restore(&self, saved: Self::Saved) -> Self {
+        Self::new(self.z, self.factory, saved, self.goal)
+    }
+
+    fn describe(&self) -> String {
+        let digits =
+            self.inputs.iter().map(|input| input.map_or('_', |v| (b'0' + v as u8) as char));
+        format!("model number {}", digits.collect::<String>())
+    }
+}
+
+impl<'a> Step<'a> {
+    fn new(z: &'a ValueRef, factory: &'a Factory, inputs: [Option<i64>; 14], goal: Goal) -> Self {
+        let value = Self::value(&inputs, goal, z, factory).ok();
+        Self { z, factory, inputs, value, goal }
+    }
+
+    fn value(
+        inputs: &[Option<i64>; 14],
+        goal: Goal,
+        variable: &ValueRef,
+        factory: &Factory,
+    ) -> Result<i64, &'static str> {
+        let mut inputs = *inputs;
+        for i in inputs.iter_mut().filter(|v| v.is_none()) {
+            *i = Some(goal.fill())
+        }
+        let value = variable.value_for_inputs(&inputs, factory)?;
+        let result = match *value.ref_value() {
+            Value::Number(n) => Ok(n),
+            _ => unreachable!(),
+        };
+        result
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Deduction {
+    Unknown,
+    Impossible,
+    In { min: i64, max: i64 },
+}
+
+/// Symbolic value of `z` once the program has run, in terms of the 14 inputs.
+fn run_program(program: &[Instruction], factory: &Factory) -> common::Result<ValueRef> {
+    let _span = debug_span!("run_program", instructions = program.len()).entered();
+    let mut input_count = 0u8;
+    let mut eql_count = 0u8;
+    let zero =
+        factory.try_new(Value::Number(0), None).map_err(|s| Error::NoSolution(s.to_string()))?;
+    let mut variables = HashMap::from([
+        (Variable::X, zero.clone()),
+        (Variable::Y, zero.clone()),
+        (Variable::Z, zero.clone()),
+        (Variable::W, zero),
+    ]);
+
+    for (line, instruction) in program.iter().enumerate() {
+        common::cancel::check()?;
+        instruction
+            .execute(&mut input_count, &mut eql_count, &mut variables, &Default::default(), factory)
+            .map_err(|s| {
+                Error::NoSolution(format!("{}: {:?} at line {}", s, instruction, line + 1))
+            })?;
+    }
+
+    Ok(variables.remove(&Variable::Z).unwrap())
+}
+
+fn find_model_number(program: &[Instruction], goal: Goal) -> common::Result<Output> {
+    let factory = Factory::new();
+
+    let z = run_program(program, &factory)?;
+
+    let name = match goal {
+        Goal::Largest => "day24-largest",
+        Goal::Smallest => "day24-smallest",
+    };
+    let start = Step::new(&z, &factory, [None; 14], goal);
+    let outcome = search::checkpoint::run(name, &(program, goal), start)?;
+    factory.trace();
+    let found =
+        outcome.found.ok_or_else(|| Error::NoSolution("no model number is valid".to_string()))?;
+    let model_number = inputs_to_string(&found.goal().inputs, Some(goal.fill()));
+    Ok(Output::from(model_number).with("expanded", outcome.stats.expanded))
+}
+
+/// An ALU program, such as MONAD.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Program(Vec<Instruction>);
+
+#[derive(Debug)]
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Model = Program;
+
+    fn parse(input: &str) -> common::Result<Self::Model> {
+        let source = Source::new(input);
+        let instructions = source
+            .records(input.lines(), |line| line.parse().map_err(|e| source.locate(line, e)))?;
+        Ok(Program(instructions))
+    }
+
+    fn part1(program: &Self::Model) -> common::Result<Answer> {
+        Self::part1_output(program).map(|output| output.answer)
+    }
+
+    fn part2(program: &Self::Model) -> common::Result<Answer> {
+        Self::part2_output(program).map(|output| output.answer)
+    }
+
+    fn part1_output(program: &Self::Model) -> common::Result<Output> {
+        find_model_number(&program.0, Goal::Largest)
+    }
+
+    fn part2_output(program: &Self::Model) -> common::Result<Output> {
+        find_model_number(&program.0, Goal::Smallest)
+    }
+}
@@ -0,0 +1,15 @@
+use common::Solution;
+use day24::Day24;
+
+// a MONAD-like program generated with known digit constraints
+const GENERATED: &str = include_str!("generated.txt");
+
+#[test]
+fn part1_generated() {
+    assert_eq!(Day24::solve_part1(GENERATED), Ok("97979348959959".into()));
+}
+
+#[test]
+fn part2_generated() {
+    assert_eq!(Day24::solve_part2(GENERATED), Ok("31317111512615".into()));
+}
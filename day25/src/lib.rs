@@ -0,0 +1,110 @@
+#![cfg_attr(feature = "pedantic", warn(clippy::pedantic))]
+#![warn(clippy::use_self)]
+#![warn(clippy::map_flatten)]
+#![warn(clippy::map_unwrap_or)]
+#![warn(deprecated_in_future)]
+#![warn(future_incompatible)]
+#![warn(noop_method_call)]
+#![warn(missing_debug_implementations)]
+#![warn(rust_2018_compatibility)]
+#![warn(rust_2021_compatibility)]
+#![warn(rust_2018_idioms)]
+#![warn(unused)]
+//#![deny(warnings)]
+
+use common::{Answer, Error, Solution};
+use grid::{Frame, Grid, Pixel};
+use std::ops::ControlFlow;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Tile {
+    South,
+    East,
+    Empty,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        let result = match value {
+            '.' => Self::Empty,
+            'v' => Self::South,
+            '>' => Self::East,
+            _ => return Err("Invalid tile"),
+        };
+        Ok(result)
+    }
+}
+
+/// Moves every sea cucumber of `herd` that has room in front of it, all at once.
+fn move_herd(map: &Grid<Tile>, herd: Tile, offset: (isize, isize)) -> Option<Grid<Tile>> {
+    let mut next = map.clone();
+    let mut changed = false;
+    for (position, &tile) in map.iter() {
+        if tile == herd {
+            let target = map.wrapping_offset(position, offset);
+            if map[target] == Tile::Empty {
+                next[target] = herd;
+                next[position] = Tile::Empty;
+                changed = true;
+            }
+        }
+    }
+    changed.then_some(next)
+}
+
+fn step(map: &Grid<Tile>) -> Option<Grid<Tile>> {
+    let east = move_herd(map, Tile::East, (1, 0));
+    let moved_east = east.is_some();
+    let map = east.unwrap_or_else(|| map.clone());
+    move_herd(&map, Tile::South, (0, 1)).or_else(|| moved_east.then_some(map))
+}
+
+fn to_pixel(tile: &Tile) -> Pixel {
+    match tile {
+        Tile::East => Pixel::new('>', [80, 200, 120]),
+        Tile::South => Pixel::new('v', [240, 140, 40]),
+        Tile::Empty => Pixel::new('.', [10, 20, 60]),
+    }
+}
+
+#[derive(Debug)]
+pub struct Day25;
+
+impl Day25 {
+    /// The sea floor at each step, until no sea cucumber can move.
+    pub fn frames(map: &Grid<Tile>) -> impl Iterator<Item = Frame> {
+        std::iter::successors(Some(map.clone()), step).map(|map| map.map(to_pixel))
+    }
+}
+
+impl Solution for Day25 {
+    type Model = Grid<Tile>;
+
+    fn parse(input: &str) -> common::Result<Self::Model> {
+        Ok(Grid::parse(input, ". > or v", |c| Tile::try_from(c).ok())?)
+    }
+
+    fn part1(map: &Self::Model) -> common::Result<Answer> {
+        let max = (1..).try_fold(map.clone(), |map, i: usize| {
+            if let Err(e) = common::cancel::check() {
+                ControlFlow::Break(Err(e))
+            } else if let Some(map) = step(&map) {
+                ControlFlow::Continue(map)
+            } else {
+                ControlFlow::Break(Ok(i))
+            }
+        });
+
+        match max {
+            ControlFlow::Break(i) => Ok(i?.into()),
+            ControlFlow::Continue(_) => unreachable!(),
+        }
+    }
+
+    fn part2(_: &Self::Model) -> common::Result<Answer> {
+        Err(Error::NoSolution("day 25 has a single puzzle".to_string()))
+    }
+}
@@ -0,0 +1,26 @@
+use common::{Error, Solution};
+use day25::Day25;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day25::solve_part1(EXAMPLE), Ok(58.into()));
+}
+
+#[test]
+fn no_part2() {
+    assert!(matches!(Day25::solve_part2(EXAMPLE), Err(Error::NoSolution(_))));
+}
+
+#[test]
+fn blocked_across_the_edges() {
+    // every sea cucumber faces another one on the opposite side of the map
+    assert_eq!(Day25::solve_part1(">v\nv>\n"), Ok(1.into()));
+}
+
+#[test]
+fn frames_until_stopped() {
+    let map = Day25::parse(EXAMPLE).unwrap();
+    assert_eq!(Day25::frames(&map).count(), 58);
+}
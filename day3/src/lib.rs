@@ -0,0 +1,91 @@
+use common::{Answer, Output, ParseError, Result, Solution, Source};
+use std::cmp::Ordering;
+
+fn compute(lines: Vec<&Vec<u8>>, x: usize, default: u8) -> Result<usize> {
+    common::cancel::check()?;
+    if lines.len() == 1 {
+        let line = lines.first().unwrap();
+        return Ok(line.iter().fold(0usize, |acc, x| acc * 2 + *x as usize));
+    }
+    // Numbers left alike after the last bit are the same number, and any of them is the rating.
+    if x == lines[0].len() {
+        return compute(lines[..1].to_vec(), x, default);
+    }
+
+    let ones: usize = lines.iter().map(|line| line[x] as usize).sum();
+    // When every number has the same bit there is nothing to filter out.
+    if ones == 0 || ones == lines.len() {
+        return compute(lines, x + 1, default);
+    }
+    let keep = match (ones * 2).cmp(&lines.len()) {
+        Ordering::Less => (default + 1) % 2,
+        Ordering::Greater | Ordering::Equal => default,
+    };
+
+    compute(lines.into_iter().filter(|line| line[x] == keep).collect(), x + 1, default)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Model = Vec<Vec<u8>>;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let source = Source::new(input);
+        let mut width = None;
+        let lines = source.records(input.lines(), |line| {
+            let bits = line
+                .char_indices()
+                .map(|(i, c)| match c {
+                    '0' => Ok(0),
+                    '1' => Ok(1),
+                    _ => Err(source.unexpected(&line[i..], "0 or 1")),
+                })
+                .collect::<std::result::Result<Vec<u8>, ParseError>>()?;
+            if bits.is_empty() {
+                return Err(source.missing(line, "a binary number"));
+            }
+            match *width.get_or_insert(bits.len()) {
+                width if bits.len() < width => {
+                    Err(source.missing(line, format!("a line of {} bits", width)))
+                }
+                width if bits.len() > width => Err(source.error(&line[width..], "end of line")),
+                _ => Ok(bits),
+            }
+        })?;
+
+        if lines.is_empty() {
+            return Err(source.end("a binary number").into());
+        }
+        Ok(lines)
+    }
+
+    fn part1(lines: &Self::Model) -> Result<Answer> {
+        Self::part1_output(lines).map(|output| output.answer)
+    }
+
+    fn part2(lines: &Self::Model) -> Result<Answer> {
+        Self::part2_output(lines).map(|output| output.answer)
+    }
+
+    fn part1_output(lines: &Self::Model) -> Result<Output> {
+        let width = lines.first().unwrap().len();
+
+        let mut gamma = 0;
+        let mut epsilon = 0;
+        for x in 0..width {
+            common::cancel::check()?;
+            let ones: usize = lines.iter().map(|line| line[x] as usize).sum();
+            let one_most_common = ones > lines.len() / 2;
+            gamma = gamma * 2 + if one_most_common { 1 } else { 0 };
+            epsilon = epsilon * 2 + if one_most_common { 0 } else { 1 };
+        }
+        Ok(Output::from(gamma * epsilon).with("gamma", gamma).with("epsilon", epsilon))
+    }
+
+    fn part2_output(lines: &Self::Model) -> Result<Output> {
+        let oxygen = compute(lines.iter().collect(), 0, 1)?;
+        let co2 = compute(lines.iter().collect(), 0, 0)?;
+        Ok(Output::from(oxygen * co2).with("oxygen", oxygen).with("co2", co2))
+    }
+}
@@ -0,0 +1,24 @@
+use common::Solution;
+use day3::Day3;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day3::solve_part1(EXAMPLE), Ok(198.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day3::solve_part2(EXAMPLE), Ok(230.into()));
+}
+
+#[test]
+fn part2_keeps_numbers_sharing_a_bit() {
+    assert_eq!(Day3::solve_part2("100\n101\n"), Ok(20.into()));
+}
+
+#[test]
+fn part2_stops_at_the_last_bit_of_duplicate_numbers() {
+    assert_eq!(Day3::solve_part2("101\n101\n"), Ok(25.into()));
+}
@@ -0,0 +1,142 @@
+use common::{input, Answer, Error, Output, ParseError, Result, Solution, Source};
+use std::collections::HashSet;
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grid {
+    marks: HashSet<(usize, usize)>,
+    rows: Vec<Vec<u8>>,
+    winner: bool,
+}
+
+impl Grid {
+    fn new(rows: Vec<Vec<u8>>) -> Self {
+        Self { marks: HashSet::new(), rows, winner: false }
+    }
+
+    fn winning_column(&self, x: usize) -> bool {
+        (0..self.rows.len()).all(|y| self.marks.contains(&(x, y)))
+    }
+
+    fn winning_row(&self, y: usize) -> bool {
+        (0..self.rows.get(y).unwrap().len()).all(|x| self.marks.contains(&(x, y)))
+    }
+
+    fn mark(&mut self, number: u8) {
+        for (y, row) in self.rows.iter().enumerate() {
+            for x in row.iter().enumerate().filter_map(|(x, value)| (*value == number).then_some(x))
+            {
+                self.marks.insert((x, y));
+                self.winner = self.winner || self.winning_column(x) || self.winning_row(y);
+            }
+        }
+    }
+
+    fn score(&self) -> usize {
+        self.rows
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter().enumerate().filter_map(move |(x, value)| {
+                    (!self.marks.contains(&(x, y))).then_some(*value as usize)
+                })
+            })
+            .sum()
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bingo {
+    draws: Vec<u8>,
+    grids: Vec<Grid>,
+}
+
+/// The final score of `grid`, winning on `draw`.
+fn score(draw: u8, grid: &Grid) -> Output {
+    let unmarked = grid.score();
+    Output::from(draw as usize * unmarked).with("draw", draw).with("unmarked", unmarked)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Model = Bingo;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let source = Source::new(input);
+        let mut sections = input::sections(input);
+        let draws = sections.next().ok_or_else(|| source.end("the drawn numbers"))?;
+        let draws = source
+            .records(draws.lines().flat_map(|line| line.split(',')), |draw| source.number(draw))?;
+
+        let grids = sections
+            .map(|board| {
+                let rows = board
+                    .lines()
+                    .map(|line| source.records(line.split_whitespace(), |n| source.number(n)));
+                Ok(Grid::new(rows.collect::<std::result::Result<_, ParseError>>()?))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Bingo { draws, grids })
+    }
+
+    fn part1(bingo: &Self::Model) -> Result<Answer> {
+        Self::part1_output(bingo).map(|output| output.answer)
+    }
+
+    fn part2(bingo: &Self::Model) -> Result<Answer> {
+        Self::part2_output(bingo).map(|output| output.answer)
+    }
+
+    fn part1_output(bingo: &Self::Model) -> Result<Output> {
+        let mut grids = bingo.grids.clone();
+
+        for &draw in &bingo.draws {
+            common::cancel::check()?;
+            for grid in grids.iter_mut() {
+                grid.mark(draw);
+                if grid.winner {
+                    return Ok(score(draw, grid));
+                }
+            }
+        }
+
+        Err(Error::NoSolution("no board wins".to_string()))
+    }
+
+    fn part2_output(bingo: &Self::Model) -> Result<Output> {
+        let mut grids = bingo.grids.clone();
+
+        let mut loser = None;
+        for &draw in &bingo.draws {
+            common::cancel::check()?;
+            match loser.as_mut() {
+                None => {
+                    grids = grids
+                        .into_iter()
+                        .filter_map(|mut grid| {
+                            grid.mark(draw);
+                            if grid.winner {
+                                None
+                            } else {
+                                Some(grid)
+                            }
+                        })
+                        .collect();
+                    if grids.len() == 1 {
+                        loser = grids.pop()
+                    }
+                }
+                Some(grid) => {
+                    grid.mark(draw);
+                    if grid.winner {
+                        return Ok(score(draw, grid));
+                    }
+                }
+            }
+        }
+
+        Err(Error::NoSolution("the last board never wins".to_string()))
+    }
+}
@@ -0,0 +1,27 @@
+use common::{Output, Solution};
+use day4::Day4;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day4::solve_part1(EXAMPLE), Ok(4512.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day4::solve_part2(EXAMPLE), Ok(1924.into()));
+}
+
+#[test]
+fn intermediate_results() {
+    let model = Day4::parse(EXAMPLE).unwrap();
+    assert_eq!(
+        Day4::part1_output(&model),
+        Ok(Output::from(4512).with("draw", 24u8).with("unmarked", 188))
+    );
+    assert_eq!(
+        Day4::part2_output(&model),
+        Ok(Output::from(1924).with("draw", 13u8).with("unmarked", 148))
+    );
+}
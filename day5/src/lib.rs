@@ -0,0 +1,51 @@
+use common::{Answer, Result, Solution, Source};
+use geometry::Vec2;
+use std::collections::HashMap;
+use std::iter::successors;
+
+/// The ends of a line of vents.
+type Line = (Vec2, Vec2);
+
+fn overlaps<'a>(lines: impl Iterator<Item = &'a Line>) -> Result<usize> {
+    let mut grid = HashMap::<_, usize>::new();
+
+    for &(start, end) in lines {
+        common::cancel::check()?;
+        let step = (end - start).signum();
+        for point in successors(Some(start), |&point| (point != end).then_some(point + step)) {
+            *grid.entry(point).or_default() += 1;
+        }
+    }
+
+    Ok(grid.values().filter(|v| **v > 1).count())
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Model = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let source = Source::new(input);
+        Ok(source.records(input.lines(), |line| {
+            let (start, end) = source.split_once(line, " -> ")?;
+            let [x1, y1] = source.integers(start)?;
+            let [x2, y2] = source.integers(end)?;
+            let (start, end) = (Vec2::new(x1, y1), Vec2::new(x2, y2));
+            let Vec2 { x, y } = (end - start).abs();
+            if x != 0 && y != 0 && x != y {
+                return Err(source.error(line, "a horizontal, vertical or diagonal line"));
+            }
+            Ok((start, end))
+        })?)
+    }
+
+    fn part1(lines: &Self::Model) -> Result<Answer> {
+        Ok(overlaps(lines.iter().filter(|(start, end)| start.x == end.x || start.y == end.y))?
+            .into())
+    }
+
+    fn part2(lines: &Self::Model) -> Result<Answer> {
+        Ok(overlaps(lines.iter())?.into())
+    }
+}
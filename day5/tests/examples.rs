@@ -0,0 +1,14 @@
+use common::Solution;
+use day5::Day5;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day5::solve_part1(EXAMPLE), Ok(5.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day5::solve_part2(EXAMPLE), Ok(12.into()));
+}
@@ -0,0 +1,74 @@
+use common::{Answer, Result, Solution, Source};
+use std::collections::HashMap;
+
+/// How many fish there are after `days`, following each fish on its own.
+pub fn simulate(ages: &[usize], days: usize) -> Result<usize> {
+    let mut ages = ages.to_vec();
+
+    for _ in 0..days {
+        common::cancel::check()?;
+        let mut births = vec![];
+        for age in ages.iter_mut() {
+            match age {
+                0 => {
+                    births.push(8);
+                    *age = 6;
+                }
+                _ => {
+                    *age -= 1;
+                }
+            }
+        }
+        ages.extend(births);
+    }
+
+    Ok(ages.len())
+}
+
+/// Like `simulate`, only counting how many fish have each age.
+pub fn count_by_age(ages: &[usize], days: usize) -> Result<usize> {
+    let mut counts_per_age: HashMap<usize, usize> = HashMap::new();
+    for &age in ages {
+        *counts_per_age.entry(age).or_default() += 1;
+    }
+
+    for _ in 0..days {
+        common::cancel::check()?;
+        let mut next = HashMap::new();
+
+        for (age, count) in counts_per_age {
+            match age {
+                0 => {
+                    *next.entry(6).or_default() += count;
+                    *next.entry(8).or_default() += count;
+                }
+                _ => {
+                    *next.entry(age - 1).or_default() += count;
+                }
+            }
+        }
+        counts_per_age = next;
+    }
+
+    Ok(counts_per_age.values().sum::<usize>())
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Model = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let source = Source::new(input);
+        let line = input.lines().next().ok_or_else(|| source.end("the ages of the fish"))?;
+        Ok(source.records(line.split(','), |age| source.number(age))?)
+    }
+
+    fn part1(ages: &Self::Model) -> Result<Answer> {
+        Ok(simulate(ages, 80)?.into())
+    }
+
+    fn part2(ages: &Self::Model) -> Result<Answer> {
+        Ok(count_by_age(ages, 256)?.into())
+    }
+}
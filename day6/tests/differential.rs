@@ -0,0 +1,12 @@
+use day6::{count_by_age, simulate};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn counting_by_age_agrees_with_simulating(
+        ages in prop::collection::vec(0..=9usize, 0..20),
+        days in 0..60usize,
+    ) {
+        prop_assert_eq!(count_by_age(&ages, days), simulate(&ages, days));
+    }
+}
@@ -0,0 +1,14 @@
+use common::Solution;
+use day6::Day6;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day6::solve_part1(EXAMPLE), Ok(5934.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day6::solve_part2(EXAMPLE), Ok(26984457539i64.into()));
+}
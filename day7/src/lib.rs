@@ -0,0 +1,52 @@
+use common::{Answer, Error, Output, Result, Solution, Source};
+use itertools::{Itertools, MinMaxResult};
+
+/// The cheapest destination, where moving a crab `diff` steps costs `cost(diff)`, or `None` when
+/// the fuel does not fit in an `i64`.
+fn align(positions: &[i64], cost: impl Fn(i64) -> Option<i64>) -> Result<Output> {
+    let (min, max) = match positions.iter().minmax() {
+        MinMaxResult::NoElements => return Err(Error::NoSolution("no positions".to_string())),
+        MinMaxResult::OneElement(position) => (*position, *position),
+        MinMaxResult::MinMax(min, max) => (*min, *max),
+    };
+
+    let (fuel, destination) = (min..=max).try_fold((i64::MAX, min), |best, destination| {
+        common::cancel::check()?;
+        let fuel = positions
+            .iter()
+            .try_fold(0i64, |fuel, position| {
+                fuel.checked_add(cost(position.checked_sub(destination)?.checked_abs()?)?)
+            })
+            .ok_or_else(|| Error::NoSolution("the fuel overflows".to_string()))?;
+        Ok::<_, Error>(best.min((fuel, destination)))
+    })?;
+
+    Ok(Output::from(fuel).with("destination", destination))
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Model = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let source = Source::new(input);
+        Ok(source.records(input.trim_end().split(','), |position| source.number(position))?)
+    }
+
+    fn part1(positions: &Self::Model) -> Result<Answer> {
+        Self::part1_output(positions).map(|output| output.answer)
+    }
+
+    fn part2(positions: &Self::Model) -> Result<Answer> {
+        Self::part2_output(positions).map(|output| output.answer)
+    }
+
+    fn part1_output(positions: &Self::Model) -> Result<Output> {
+        align(positions, Some)
+    }
+
+    fn part2_output(positions: &Self::Model) -> Result<Output> {
+        align(positions, |diff| Some(diff.checked_mul(diff.checked_add(1)?)? / 2))
+    }
+}
@@ -0,0 +1,21 @@
+use common::{Output, Solution};
+use day7::Day7;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day7::solve_part1(EXAMPLE), Ok(37.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day7::solve_part2(EXAMPLE), Ok(168.into()));
+}
+
+#[test]
+fn intermediate_results() {
+    let model = Day7::parse(EXAMPLE).unwrap();
+    assert_eq!(Day7::part1_output(&model), Ok(Output::from(37).with("destination", 2)));
+    assert_eq!(Day7::part2_output(&model), Ok(Output::from(168).with("destination", 5)));
+}
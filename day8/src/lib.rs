@@ -0,0 +1,186 @@
+use common::{Answer, Error, ParseError, Result, Solution, Source};
+use itertools::Itertools;
+use maplit::hashmap;
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+use std::ops::ControlFlow::{Break, Continue};
+
+fn permutations<F: FnMut(Vec<u8>) -> ControlFlow<Vec<usize>>>(
+    result: Vec<u8>,
+    wire_to_segment: Vec<Vec<u8>>,
+    f: &mut F,
+) -> ControlFlow<Vec<usize>> {
+    let (segments, wire_to_segment) = match wire_to_segment.split_first() {
+        None => {
+            f(result)?;
+            return Continue(());
+        }
+        Some((segments, wire_to_segment)) => (segments, wire_to_segment),
+    };
+
+    for &segment in segments {
+        let mut result = result.clone();
+        let mut wire_to_segment = wire_to_segment.to_vec();
+
+        for segments in wire_to_segment.iter_mut() {
+            segments.retain(|&segment_| segment_ != segment);
+        }
+
+        result.push(segment);
+        permutations(result, wire_to_segment, f)?;
+    }
+
+    Continue(())
+}
+
+/// The ten unique signal patterns and the four displayed ones, as sorted wire indexes.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Entry {
+    patterns: Vec<Vec<u8>>,
+    displayed_patterns: Vec<Vec<u8>>,
+}
+
+impl Entry {
+    fn decode(&self) -> Result<usize> {
+        let digit_to_segments: Vec<Vec<u8>> = vec![
+            vec![0, 1, 2, 4, 5, 6],
+            vec![2, 5],
+            vec![0, 2, 3, 4, 6],
+            vec![0, 2, 3, 5, 6],
+            vec![1, 2, 3, 5],
+            vec![0, 1, 3, 5, 6],
+            vec![0, 1, 3, 4, 5, 6],
+            vec![0, 2, 5],
+            vec![0, 1, 2, 3, 4, 5, 6],
+            vec![0, 1, 2, 3, 5, 6],
+        ];
+        let length_to_digit: HashMap<usize, u8> = hashmap! {
+            2 => 1,
+            4 => 4,
+            3 => 7,
+            7 => 8,
+        };
+
+        let patterns = &self.patterns;
+        let mut wire_to_segments: Vec<Vec<u8>> = (0..=6).map(|_| (0..=6).collect()).collect();
+
+        for pattern in patterns.iter() {
+            if let Some(digit) = length_to_digit.get(&pattern.len()) {
+                let segments_for_digit = digit_to_segments.get(*digit as usize).unwrap();
+                for (wire, possible_segments) in wire_to_segments.iter_mut().enumerate() {
+                    if pattern.contains(&(wire as u8)) {
+                        // wires appearing in the current pattern can only belong to one of the segments for the current digit
+                        possible_segments.retain(|segment| segments_for_digit.contains(segment));
+                    } else {
+                        // wires not appearing in the current pattern cannot belong to the segments of the digit
+                        possible_segments.retain(|segment| !segments_for_digit.contains(segment));
+                    }
+                }
+            }
+        }
+
+        let pattern_indexes_to_digit = if let Break(value) =
+            permutations(vec![], wire_to_segments, &mut |wire_to_segment: Vec<u8>| {
+                let pattern_index_to_digit = patterns
+                    .iter()
+                    .filter_map(|wires| {
+                        let segments: Vec<u8> = wires
+                            .iter()
+                            .map(|wire| *wire_to_segment.get(*wire as usize).unwrap())
+                            .sorted()
+                            .collect();
+                        digit_to_segments.iter().position(|x| x.eq(&segments))
+                    })
+                    .collect_vec();
+                if pattern_index_to_digit.len() == patterns.len() {
+                    Break(pattern_index_to_digit)
+                } else {
+                    Continue(())
+                }
+            }) {
+            value
+        } else {
+            return Err(Error::NoSolution("no wiring matches the patterns".to_string()));
+        };
+
+        let mut number = 0;
+        for displayed_pattern in &self.displayed_patterns {
+            let pattern_index =
+                patterns
+                    .iter()
+                    .position(|pattern| pattern.eq(displayed_pattern))
+                    .ok_or_else(|| Error::NoSolution("displayed pattern is unknown".to_string()))?;
+            let &digit = pattern_indexes_to_digit.get(pattern_index).unwrap();
+            number = number * 10 + digit;
+        }
+
+        Ok(number)
+    }
+}
+
+/// Reads `count` patterns, each a set of wires from `a` to `g`.
+fn read_patterns<'a>(
+    source: Source<'a>,
+    words: &mut impl Iterator<Item = &'a str>,
+    count: usize,
+) -> std::result::Result<Vec<Vec<u8>>, ParseError> {
+    (0..count)
+        .map(|_| {
+            let word = words.next().ok_or_else(|| source.end("a signal pattern"))?;
+            word.char_indices()
+                .map(|(i, c)| match c {
+                    'a'..='g' => Ok(c as u8 - b'a'),
+                    _ => Err(source.unexpected(&word[i..], "a wire from a to g")),
+                })
+                .collect::<std::result::Result<Vec<u8>, _>>()
+                .map(|wires| wires.into_iter().sorted().collect())
+        })
+        .collect()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Model = Vec<Entry>;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        // entries may be wrapped after the `|`, so they are read word by word
+        let source = Source::new(input);
+        let mut words = input.split_whitespace().peekable();
+        let mut entries = vec![];
+        while words.peek().is_some() {
+            let patterns = read_patterns(source, &mut words, 10)?;
+            match words.next() {
+                Some("|") => {}
+                Some(word) => return Err(source.error(word, "\"|\"").into()),
+                None => return Err(source.end("\"|\"").into()),
+            }
+            let displayed_patterns = read_patterns(source, &mut words, 4)?;
+            entries.push(Entry { patterns, displayed_patterns });
+        }
+        Ok(entries)
+    }
+
+    fn part1(entries: &Self::Model) -> Result<Answer> {
+        let count: usize = common::cancel::checked(entries)
+            .map(|entry| {
+                entry
+                    .displayed_patterns
+                    .iter()
+                    .filter(|pattern| matches!(pattern.len(), 2 | 4 | 3 | 7))
+                    .count()
+            })
+            .sum();
+        common::cancel::check()?;
+        Ok(count.into())
+    }
+
+    fn part2(entries: &Self::Model) -> Result<Answer> {
+        let mut sum = 0;
+        for entry in entries {
+            common::cancel::check()?;
+            sum += entry.decode()?;
+        }
+        Ok(sum.into())
+    }
+}
@@ -0,0 +1,21 @@
+use common::Solution;
+use day8::Day8;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day8::solve_part1(EXAMPLE), Ok(26.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day8::solve_part2(EXAMPLE), Ok(61229.into()));
+}
+
+#[test]
+fn entry_wrapped_after_separator() {
+    let entry = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab |\n\
+                 cdfeb fcadb cdfeb cdbaf\n";
+    assert_eq!(Day8::solve_part2(entry), Ok(5353.into()));
+}
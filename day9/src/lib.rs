@@ -0,0 +1,61 @@
+use common::{Answer, Error, Result, Solution};
+use grid::{Grid, Neighbourhood, Position};
+
+fn is_lowpoint(map: &Grid<u8>, position: Position) -> bool {
+    let current = map[position];
+    map.neighbours(position, Neighbourhood::Orthogonal).all(|neighbour| current < map[neighbour])
+}
+
+fn mark_bassin(map: &Grid<u8>, bassins: &mut Grid<Option<usize>>, position: Position, id: usize) {
+    bassins[position] = Some(id);
+    for neighbour in map.neighbours(position, Neighbourhood::Orthogonal) {
+        if bassins[neighbour].is_none() && (map[position]..9).contains(&map[neighbour]) {
+            mark_bassin(map, bassins, neighbour, id);
+        }
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Model = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Ok(Grid::parse_digits(input)?)
+    }
+
+    fn part1(map: &Self::Model) -> Result<Answer> {
+        let risks: usize = common::cancel::checked(map.positions())
+            .filter(|&position| is_lowpoint(map, position))
+            .map(|position| map[position] as usize + 1)
+            .sum();
+        common::cancel::check()?;
+        Ok(risks.into())
+    }
+
+    fn part2(map: &Self::Model) -> Result<Answer> {
+        let mut bassins = map.map(|_| None);
+
+        let mut id = 0;
+        for position in map.positions() {
+            common::cancel::check()?;
+            if is_lowpoint(map, position) {
+                mark_bassin(map, &mut bassins, position, id);
+                id += 1;
+            }
+        }
+
+        let mut sizes = vec![0; id];
+
+        for id in bassins.cells().flatten() {
+            sizes[*id] += 1;
+        }
+
+        sizes.sort_unstable();
+
+        if sizes.len() < 3 {
+            return Err(Error::NoSolution("fewer than three bassins".to_string()));
+        }
+        Ok(sizes[sizes.len() - 3..sizes.len()].iter().product::<usize>().into())
+    }
+}
@@ -0,0 +1,14 @@
+use common::Solution;
+use day9::Day9;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day9::solve_part1(EXAMPLE), Ok(15.into()));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day9::solve_part2(EXAMPLE), Ok(1134.into()));
+}
@@ -0,0 +1,17 @@
+use crate::{lines, Generated, Rng};
+use rand::Rng as _;
+
+/// `size` depth measurements, going down overall like the sea floor.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut depth = rng.gen_range(100..200);
+    let depths = (0..size)
+        .map(|_| {
+            depth = (depth + rng.gen_range(-10..=20)).max(0);
+            depth
+        })
+        .collect::<Vec<i32>>();
+
+    let increases = depths.windows(2).filter(|pair| pair[1] > pair[0]).count();
+    let window_increases = depths.windows(4).filter(|window| window[3] > window[0]).count();
+    Generated::with_answers(lines(&depths), increases, window_increases)
+}
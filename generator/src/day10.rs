@@ -0,0 +1,61 @@
+use crate::{lines, Generated, Rng};
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+const CHUNKS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+const CORRUPTION_SCORES: [u64; 4] = [3, 57, 1197, 25137];
+/// Deep enough for long lines, shallow enough for completion scores to fit in an `i64`.
+const MAX_DEPTH: usize = 20;
+
+/// Random chunks, returning the indices of those left open.
+fn chunks(rng: &mut Rng, line: &mut String, length: usize) -> Vec<usize> {
+    let mut open = vec![];
+    for _ in 0..length {
+        if open.len() < MAX_DEPTH && (open.is_empty() || rng.gen_ratio(3, 5)) {
+            let chunk = rng.gen_range(0..CHUNKS.len());
+            line.push(CHUNKS[chunk].0);
+            open.push(chunk);
+        } else if let Some(chunk) = open.pop() {
+            line.push(CHUNKS[chunk].1);
+        }
+    }
+    open
+}
+
+/// `size` lines of navigation subsystem, an odd number of them incomplete and the others
+/// corrupted.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(1);
+    let incomplete = size.div_ceil(2) | 1;
+
+    let mut corruption_score = 0;
+    let mut completion_scores = vec![];
+    let mut navigation = (0..size)
+        .map(|i| {
+            let mut line = String::new();
+            let length = rng.gen_range(10..100);
+            let mut open = chunks(rng, &mut line, length);
+            while open.is_empty() {
+                open = chunks(rng, &mut line, 10);
+            }
+
+            if i < incomplete {
+                let score = open.iter().rev().fold(0, |score, &chunk| score * 5 + chunk as u64 + 1);
+                completion_scores.push(score);
+            } else {
+                let expected = *open.last().expect("a chunk is open");
+                let found = (expected + rng.gen_range(1..CHUNKS.len())) % CHUNKS.len();
+                line.push(CHUNKS[found].1);
+                corruption_score += CORRUPTION_SCORES[found];
+                let rest = rng.gen_range(0..20);
+                chunks(rng, &mut line, rest);
+            }
+            line
+        })
+        .collect::<Vec<_>>();
+    navigation.shuffle(rng);
+
+    completion_scores.sort_unstable();
+    let middle = completion_scores[completion_scores.len() / 2];
+    Generated::with_answers(lines(navigation), corruption_score, middle)
+}
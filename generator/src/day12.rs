@@ -0,0 +1,44 @@
+use crate::{lines, Generated, Rng};
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+use std::collections::BTreeSet;
+
+fn name(i: usize, first: u8) -> String {
+    String::from_utf8(vec![first + (i / 26 % 26) as u8, first + (i % 26) as u8])
+        .expect("cave names are ASCII letters")
+}
+
+/// A cave system of `size` small caves and about a third as many big ones.
+///
+/// Big caves are never connected to each other, which would make the paths infinite. The
+/// number of paths grows very fast with the size: the puzzle has 6 small caves.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(1);
+    let small = (0..size).map(|i| name(i, b'a')).collect::<Vec<_>>();
+    let big = (0..size / 3 + 1).map(|i| name(i, b'A')).collect::<Vec<_>>();
+    let caves = small.iter().chain(&big).collect::<Vec<_>>();
+
+    let mut connections = BTreeSet::new();
+    for cave in &big {
+        for _ in 0..rng.gen_range(2..=3) {
+            let other = small.choose(rng).expect("there is a small cave");
+            connections.insert((cave.clone(), other.clone()));
+        }
+    }
+    for _ in 0..size {
+        let pair = small.choose_multiple(rng, 2).cloned().collect::<Vec<_>>();
+        if let [from, to] = &pair[..] {
+            connections.insert((from.clone(), to.clone()));
+        }
+    }
+    for end in ["start", "end"] {
+        for _ in 0..rng.gen_range(1..=2) {
+            let &cave = caves.choose(rng).expect("there is a cave");
+            connections.insert((end.to_string(), cave.clone()));
+        }
+    }
+
+    let mut connections = connections.into_iter().collect::<Vec<_>>();
+    connections.shuffle(rng);
+    Generated::new(lines(connections.iter().map(|(from, to)| format!("{}-{}", from, to))))
+}
@@ -0,0 +1,76 @@
+use crate::{Generated, Rng};
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+use std::collections::BTreeSet;
+
+/// The size of the folded paper, about the size of the puzzle's eight letters.
+const FOLDED: (i32, i32) = (40, 6);
+/// How many times the paper is folded along each axis.
+const FOLDS: usize = 4;
+
+/// About `size` dots, unfolded from a random code so that no dot ever lands on a fold line.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    // Fold along x first, then alternate, each fold halving the paper.
+    let (mut width, mut height) = FOLDED;
+    let mut folds = vec![];
+    for i in (0..2 * FOLDS).rev() {
+        if i % 2 == 0 {
+            folds.push(('x', width));
+            width = 2 * width + 1;
+        } else {
+            folds.push(('y', height));
+            height = 2 * height + 1;
+        }
+    }
+    folds.reverse();
+
+    let letters = (0..FOLDED.1)
+        .flat_map(|y| (0..FOLDED.0).map(move |x| (x, y)))
+        .filter(|_| rng.gen_ratio(2, 5))
+        .collect::<Vec<_>>();
+
+    let mut code = BTreeSet::new();
+    let mut dots = BTreeSet::new();
+    for _ in 0..size {
+        let Some(&(mut x, mut y)) = letters.choose(rng) else {
+            break;
+        };
+        code.insert((y, x));
+        for &(axis, at) in folds.iter().rev() {
+            if rng.gen() {
+                match axis {
+                    'x' => x = 2 * at - x,
+                    _ => y = 2 * at - y,
+                }
+            }
+        }
+        dots.insert((x, y));
+    }
+
+    let (axis, at) = folds[0];
+    let first_fold = dots
+        .iter()
+        .map(|&(x, y)| match axis {
+            'x' if x > at => (2 * at - x, y),
+            'y' if y > at => (x, 2 * at - y),
+            _ => (x, y),
+        })
+        .collect::<BTreeSet<_>>();
+
+    let max_x = code.iter().map(|&(_, x)| x).max().unwrap_or(0);
+    let max_y = code.iter().map(|&(y, _)| y).max().unwrap_or(0);
+    let rendered = (0..=max_y)
+        .map(|y| (0..=max_x).map(|x| if code.contains(&(y, x)) { '#' } else { '.' }).collect())
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let mut input = String::new();
+    for (x, y) in &dots {
+        input.push_str(&format!("{},{}\n", x, y));
+    }
+    input.push('\n');
+    for (axis, at) in &folds {
+        input.push_str(&format!("fold along {}={}\n", axis, at));
+    }
+    Generated::with_answers(input, first_fold.len(), rendered)
+}
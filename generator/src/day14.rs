@@ -0,0 +1,18 @@
+use crate::{lines, Generated, Rng};
+use rand::Rng as _;
+
+/// How many different elements make up the polymer, as in the puzzle.
+const ELEMENTS: u8 = 10;
+
+/// A polymer template of `size` elements, with an insertion rule for every pair.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let element = |rng: &mut Rng| char::from(b'A' + rng.gen_range(0..ELEMENTS));
+    let template = (0..size.max(2)).map(|_| element(rng)).collect::<String>();
+
+    let rules = (0..ELEMENTS).flat_map(|a| (0..ELEMENTS).map(move |b| (a, b))).map(|(a, b)| {
+        format!("{}{} -> {}", char::from(b'A' + a), char::from(b'A' + b), element(rng))
+    });
+    let rules = lines(rules.collect::<Vec<_>>());
+
+    Generated::new(format!("{}\n\n{}", template, rules))
+}
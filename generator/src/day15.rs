@@ -0,0 +1,7 @@
+use crate::{map, Generated, Rng};
+use rand::Rng as _;
+
+/// A `size` by `size` map of risk levels from 1 to 9.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    Generated::new(map(size, size, |_, _| char::from(b'0' + rng.gen_range(1..10))))
+}
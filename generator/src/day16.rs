@@ -0,0 +1,94 @@
+use crate::{Generated, Rng};
+use rand::Rng as _;
+
+/// A packet as bits, with the sum of its versions and its value.
+struct Packet {
+    bits: String,
+    versions: u64,
+    value: u64,
+}
+
+fn literal(rng: &mut Rng, value: u64) -> Packet {
+    let version = rng.gen_range(0..8);
+    let mut bits = format!("{:03b}100", version);
+    let groups = (0..16).rev().map(|i| (value >> (4 * i)) & 0xf).skip_while(|&g| g == 0);
+    let mut groups = groups.collect::<Vec<_>>();
+    if groups.is_empty() {
+        groups.push(0);
+    }
+    for (i, group) in groups.iter().enumerate() {
+        let last = i + 1 == groups.len();
+        bits.push_str(&format!("{}{:04b}", if last { 0 } else { 1 }, group));
+    }
+    Packet { bits, versions: version, value }
+}
+
+fn operator(rng: &mut Rng, type_id: u64, children: Vec<Packet>) -> Packet {
+    let values = children.iter().map(|child| child.value);
+    let value = match type_id {
+        0 => values.sum(),
+        1 => values.product(),
+        2 => values.min().expect("an operator has children"),
+        3 => values.max().expect("an operator has children"),
+        5 => (children[0].value > children[1].value) as u64,
+        6 => (children[0].value < children[1].value) as u64,
+        _ => (children[0].value == children[1].value) as u64,
+    };
+
+    let version = rng.gen_range(0..8);
+    let content = children.iter().map(|child| child.bits.as_str()).collect::<String>();
+    let length = if content.len() < 1 << 15 && rng.gen() {
+        format!("0{:015b}", content.len())
+    } else {
+        format!("1{:011b}", children.len())
+    };
+    Packet {
+        bits: format!("{:03b}{:03b}{}{}", version, type_id, length, content),
+        versions: version + children.iter().map(|child| child.versions).sum::<u64>(),
+        value,
+    }
+}
+
+/// A packet of about `budget` packets. Products only hold small literals, so that values stay
+/// far from overflowing.
+fn packet(rng: &mut Rng, budget: &mut usize, depth: usize) -> Packet {
+    if *budget <= 1 || depth >= 8 || (depth > 0 && rng.gen_ratio(1, 4)) {
+        *budget = budget.saturating_sub(1);
+        let value = rng.gen_range(0..1 << 16);
+        return literal(rng, value);
+    }
+    *budget -= 1;
+
+    let type_id = [0, 1, 2, 3, 5, 6, 7][rng.gen_range(0..7)];
+    let children = match type_id {
+        1 => (0..rng.gen_range(1..=3))
+            .map(|_| {
+                *budget = budget.saturating_sub(1);
+                let value = rng.gen_range(0..=100);
+                literal(rng, value)
+            })
+            .collect(),
+        5..=7 => (0..2).map(|_| packet(rng, budget, depth + 1)).collect(),
+        _ => (0..rng.gen_range(1..=4)).map(|_| packet(rng, budget, depth + 1)).collect(),
+    };
+    operator(rng, type_id, children)
+}
+
+/// A transmission of about `size` packets, with the sum of their versions and its value.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut budget = size;
+    let Packet { mut bits, versions, value } = packet(rng, &mut budget, 0);
+    while !bits.len().is_multiple_of(4) {
+        bits.push('0');
+    }
+
+    let hex = bits
+        .as_bytes()
+        .chunks(4)
+        .map(|nibble| {
+            let nibble = std::str::from_utf8(nibble).expect("bits are ASCII digits");
+            format!("{:X}", u8::from_str_radix(nibble, 2).expect("bits are binary digits"))
+        })
+        .collect::<String>();
+    Generated::with_answers(hex + "\n", versions, value)
+}
@@ -0,0 +1,21 @@
+use crate::{Generated, Rng};
+use rand::Rng as _;
+
+/// A target area reaching `size` units below the launcher.
+///
+/// Some horizontal velocity always stalls above the target, so the highest trajectory falls
+/// straight through its bottom row and the best height is known.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let stall = rng.gen_range(5..=20i64);
+    let stall_x = stall * (stall + 1) / 2;
+    let x_min = stall_x - rng.gen_range(0..=stall / 2);
+    let x_max = stall_x + rng.gen_range(0..=30);
+
+    let depth = size.max(5) as i64;
+    let y_min = -depth;
+    let y_max = y_min + rng.gen_range(1..depth);
+
+    let input = format!("target area: x={}..{}, y={}..{}\n", x_min, x_max, y_min, y_max);
+    let best_height = (depth - 1) * depth / 2;
+    Generated { input, part1: Some(best_height.into()), part2: None }
+}
@@ -0,0 +1,24 @@
+use crate::{lines, Generated, Rng};
+use rand::Rng as _;
+
+/// A pair nested in `depth` others.
+fn pair(rng: &mut Rng, depth: usize) -> String {
+    let left = element(rng, depth + 1);
+    let right = element(rng, depth + 1);
+    format!("[{},{}]", left, right)
+}
+
+/// Either a pair or a regular number, keeping the snailfish number reduced: no pair nested in
+/// four others, no regular number above 9.
+fn element(rng: &mut Rng, depth: usize) -> String {
+    if depth < 4 && rng.gen_ratio(3, 5) {
+        pair(rng, depth)
+    } else {
+        rng.gen_range(0..10).to_string()
+    }
+}
+
+/// `size` snailfish numbers to add up.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    Generated::new(lines((0..size.max(1)).map(|_| pair(rng, 0))))
+}
@@ -0,0 +1,108 @@
+use crate::{Generated, Rng};
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+use std::collections::BTreeSet;
+
+type Position = [i32; 3];
+type Rotation = [[i32; 3]; 3];
+
+/// How far a scanner sees along each axis.
+const RANGE: i32 = 1000;
+/// Enough beacons in common between neighbouring scanners for them to be matched.
+const SHARED: usize = 14;
+/// Beacons placed around each scanner besides those it shares.
+const OWN: usize = 12;
+
+/// The 24 orientations a scanner can face.
+fn rotations() -> Vec<Rotation> {
+    let permutations = [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+    let mut rotations = vec![];
+    for permutation in permutations {
+        for signs in 0..8 {
+            let mut m = [[0; 3]; 3];
+            for (i, &j) in permutation.iter().enumerate() {
+                m[i][j] = if (signs >> i) & 1 == 0 { 1 } else { -1 };
+            }
+            let determinant = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+                - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+                + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+            if determinant == 1 {
+                rotations.push(m);
+            }
+        }
+    }
+    rotations
+}
+
+fn around(rng: &mut Rng, center: Position, radius: i32) -> Position {
+    center.map(|c| c + rng.gen_range(-radius..=radius))
+}
+
+fn chebyshev(a: Position, b: Position) -> i32 {
+    (0..3).map(|i| (a[i] - b[i]).abs()).max().unwrap_or(0)
+}
+
+fn manhattan(a: Position, b: Position) -> i32 {
+    (0..3).map(|i| (a[i] - b[i]).abs()).sum()
+}
+
+/// `size` scanners facing random ways, each sharing beacons with one placed before it.
+///
+/// The beacons are all seen by some scanner, so the answers are how many were placed and the
+/// largest distance between the scanners.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut scanners = vec![[0; 3]];
+    let mut beacons = BTreeSet::new();
+    while scanners.len() < size.max(1) {
+        // Spread the scanners out, so that each sees about as many beacons as in the puzzle.
+        let mut parent = scanners[0];
+        let mut scanner = parent;
+        for _ in 0..100 {
+            parent = *scanners.choose(rng).expect("there is a scanner");
+            scanner = around(rng, parent, RANGE + 200);
+            if scanners.iter().all(|other| chebyshev(*other, scanner) >= RANGE) {
+                break;
+            }
+        }
+
+        let low = [0, 1, 2].map(|i| parent[i].max(scanner[i]) - RANGE);
+        let high = [0, 1, 2].map(|i| parent[i].min(scanner[i]) + RANGE);
+        for _ in 0..SHARED {
+            beacons.insert([0, 1, 2].map(|i| rng.gen_range(low[i]..=high[i])));
+        }
+        scanners.push(scanner);
+    }
+    for &scanner in &scanners {
+        for _ in 0..OWN {
+            beacons.insert(around(rng, scanner, RANGE));
+        }
+    }
+
+    let rotations = rotations();
+    let mut input = String::new();
+    for (k, scanner) in scanners.iter().enumerate() {
+        let rotation = if k == 0 { rotations[0] } else { *rotations.choose(rng).expect("24") };
+        let mut seen = beacons
+            .iter()
+            .map(|beacon| [0, 1, 2].map(|i| beacon[i] - scanner[i]))
+            .filter(|relative| relative.iter().all(|c| c.abs() <= RANGE))
+            .map(|relative| rotation.map(|row| (0..3).map(|j| row[j] * relative[j]).sum::<i32>()))
+            .collect::<Vec<_>>();
+        seen.shuffle(rng);
+
+        if k > 0 {
+            input.push('\n');
+        }
+        input.push_str(&format!("--- scanner {} ---\n", k));
+        for [x, y, z] in seen {
+            input.push_str(&format!("{},{},{}\n", x, y, z));
+        }
+    }
+
+    let distance = scanners
+        .iter()
+        .flat_map(|&a| scanners.iter().map(move |&b| manhattan(a, b)))
+        .max()
+        .unwrap_or(0);
+    Generated::with_answers(input, beacons.len(), distance)
+}
@@ -0,0 +1,37 @@
+use crate::{lines, Generated, Rng};
+use rand::Rng as _;
+
+/// `size` submarine commands, never taking the submarine above the surface.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let (mut position, mut depth) = (0i64, 0i64);
+    let (mut aim, mut aimed_depth) = (0i64, 0i64);
+
+    let commands = (0..size).map(|_| {
+        let units = rng.gen_range(1..=9);
+        match rng.gen_range(0..3) {
+            0 => {
+                position += units;
+                aimed_depth += aim * units;
+                format!("forward {}", units)
+            }
+            1 => {
+                depth += units;
+                aim += units;
+                format!("down {}", units)
+            }
+            _ if depth >= units => {
+                depth -= units;
+                aim -= units;
+                format!("up {}", units)
+            }
+            _ => {
+                depth += units;
+                aim += units;
+                format!("down {}", units)
+            }
+        }
+    });
+    let input = lines(commands);
+
+    Generated::with_answers(input, position * depth, position * aimed_depth)
+}
@@ -0,0 +1,23 @@
+use crate::{map, Generated, Rng};
+use rand::Rng as _;
+
+fn pixel(rng: &mut Rng) -> char {
+    if rng.gen() {
+        '#'
+    } else {
+        '.'
+    }
+}
+
+/// A random enhancement algorithm and a `size` by `size` image.
+///
+/// When the algorithm lights the dark background it darkens a lit one, as in the puzzle, so
+/// that the number of lit pixels stays finite.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut algorithm = (0..1 << 9).map(|_| pixel(rng)).collect::<Vec<_>>();
+    if algorithm[0] == '#' {
+        algorithm[(1 << 9) - 1] = '.';
+    }
+    let image = map(size.max(1), size.max(1), |_, _| pixel(rng));
+    Generated::new(format!("{}\n\n{}", algorithm.into_iter().collect::<String>(), image))
+}
@@ -0,0 +1,11 @@
+use crate::{Generated, Rng};
+use rand::Rng as _;
+
+/// Random starting positions; there is nothing to size.
+pub fn generate(rng: &mut Rng, _size: usize) -> Generated {
+    Generated::new(format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}\n",
+        rng.gen_range(1..=10),
+        rng.gen_range(1..=10)
+    ))
+}
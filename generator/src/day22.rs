@@ -0,0 +1,21 @@
+use crate::{lines, Generated, Rng};
+use rand::Rng as _;
+
+/// How many of the first steps stay in the initialization region, as in the puzzle.
+const INITIALIZATION: usize = 20;
+
+fn range(rng: &mut Rng, bound: i32, length: i32) -> (i32, i32) {
+    let from = rng.gen_range(-bound..=bound - length);
+    (from, from + rng.gen_range(0..=length))
+}
+
+/// `size` reboot steps, the first ones within 50 cubes of the origin and the others far wider.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let steps = (0..size).map(|i| {
+        let (bound, length) = if i < INITIALIZATION { (50, 40) } else { (100_000, 40_000) };
+        let switch = if i == 0 || rng.gen_ratio(2, 3) { "on" } else { "off" };
+        let [(x1, x2), (y1, y2), (z1, z2)] = [(); 3].map(|_| range(rng, bound, length));
+        format!("{} x={}..{},y={}..{},z={}..{}", switch, x1, x2, y1, y2, z1, z2)
+    });
+    Generated::new(lines(steps))
+}
@@ -0,0 +1,16 @@
+use crate::{Generated, Rng};
+use rand::seq::SliceRandom;
+
+/// Amphipods shuffled between the side rooms, never all already home; the burrow has a fixed
+/// size.
+pub fn generate(rng: &mut Rng, _size: usize) -> Generated {
+    let mut amphipods = *b"ABCDABCD";
+    while amphipods == *b"ABCDABCD" {
+        amphipods.shuffle(rng);
+    }
+    let [a, b, c, d, e, f, g, h] = amphipods.map(char::from);
+    Generated::new(format!(
+        "#############\n#...........#\n###{}#{}#{}#{}###\n  #{}#{}#{}#{}#\n  #########\n",
+        a, b, c, d, e, f, g, h
+    ))
+}
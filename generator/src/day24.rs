@@ -0,0 +1,72 @@
+use crate::{lines, Generated, Rng};
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+/// The 18 instructions run for each digit of the model number.
+fn block(divisor: i64, check: i64, offset: i64) -> [String; 18] {
+    [
+        "inp w".to_string(),
+        "mul x 0".to_string(),
+        "add x z".to_string(),
+        "mod x 26".to_string(),
+        format!("div z {}", divisor),
+        format!("add x {}", check),
+        "eql x w".to_string(),
+        "eql x 0".to_string(),
+        "mul y 0".to_string(),
+        "add y 25".to_string(),
+        "mul y x".to_string(),
+        "add y 1".to_string(),
+        "mul z y".to_string(),
+        "mul y 0".to_string(),
+        "add y w".to_string(),
+        format!("add y {}", offset),
+        "mul y x".to_string(),
+        "add z y".to_string(),
+    ]
+}
+
+/// A MONAD-like program, checking 14 digits; there is nothing to size.
+///
+/// Like the puzzle's, the program pushes seven digits on a base 26 stack and pops the seven
+/// others, each pop only passing when its digit differs from the pushed one by a chosen amount.
+/// The largest and smallest valid model numbers follow from those differences.
+pub fn generate(rng: &mut Rng, _size: usize) -> Generated {
+    let mut operations = [true; 7].into_iter().chain([false; 7]).collect::<Vec<_>>();
+    // A random balanced sequence of pushes and pops.
+    let balanced = |operations: &[bool]| {
+        let mut depth = 0;
+        operations.iter().all(|&push| {
+            depth += if push { 1 } else { -1 };
+            depth >= 0
+        })
+    };
+    operations.shuffle(rng);
+    while !balanced(&operations) {
+        operations.shuffle(rng);
+    }
+
+    let (mut largest, mut smallest) = ([0; 14], [0; 14]);
+    let mut pushed = vec![];
+    let mut program = vec![];
+    for (i, push) in operations.into_iter().enumerate() {
+        let offset = rng.gen_range(1..=16);
+        if push {
+            pushed.push((i, offset));
+            program.extend(block(1, rng.gen_range(10..=15), offset));
+        } else {
+            let (j, pushed_offset) = pushed.pop().expect("pops are balanced by pushes");
+            // Digit `i` must be digit `j` plus `difference`.
+            let difference = rng.gen_range(-8..=8);
+            program.extend(block(26, difference - pushed_offset, offset));
+
+            largest[j] = 9.min(9 - difference);
+            largest[i] = largest[j] + difference;
+            smallest[j] = 1.max(1 - difference);
+            smallest[i] = smallest[j] + difference;
+        }
+    }
+
+    let digits = |number: [i64; 14]| number.iter().map(|d| d.to_string()).collect::<String>();
+    Generated::with_answers(lines(program), digits(largest), digits(smallest))
+}
@@ -0,0 +1,8 @@
+use crate::{map, Generated, Rng};
+use rand::Rng as _;
+
+/// A `size` by `size` sea floor, with as many east-facing and south-facing sea cucumbers as
+/// empty cells.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    Generated::new(map(size, size, |_, _| ['>', 'v', '.'][rng.gen_range(0..3)]))
+}
@@ -0,0 +1,10 @@
+use crate::{lines, Generated, Rng};
+use rand::seq::index;
+
+/// `size` distinct binary numbers, wide enough to be picked among twice as many.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(1);
+    let width = (usize::BITS - (2 * size - 1).leading_zeros()).max(5) as usize;
+    let numbers = index::sample(rng, 1 << width, size);
+    Generated::new(lines(numbers.iter().map(|n| format!("{:0width$b}", n, width = width))))
+}
@@ -0,0 +1,24 @@
+use crate::{Generated, Rng};
+use rand::seq::{index, SliceRandom};
+
+/// Numbers on the boards, as in the puzzle.
+const NUMBERS: usize = 100;
+
+/// `size` bingo boards, with every number of the boards drawn in a random order.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut draws = (0..NUMBERS).collect::<Vec<_>>();
+    draws.shuffle(rng);
+
+    let mut input = draws.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(",");
+    input.push('\n');
+    for _ in 0..size {
+        let board = index::sample(rng, NUMBERS, 25).into_vec();
+        input.push('\n');
+        for row in board.chunks(5) {
+            let row = row.iter().map(|n| format!("{:2}", n)).collect::<Vec<_>>();
+            input.push_str(&row.join(" "));
+            input.push('\n');
+        }
+    }
+    Generated::new(input)
+}
@@ -0,0 +1,38 @@
+use crate::{lines, Generated, Rng};
+use rand::Rng as _;
+
+const SIZE: i32 = 1000;
+
+fn sign(rng: &mut Rng) -> i32 {
+    if rng.gen() {
+        1
+    } else {
+        -1
+    }
+}
+
+/// `size` horizontal, vertical and diagonal vent lines on a 1000 by 1000 floor.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let vents = (0..size).map(|_| {
+        let (x1, y1) = (rng.gen_range(0..SIZE), rng.gen_range(0..SIZE));
+        let length = rng.gen_range(1..SIZE / 2);
+        let (dx, dy) = match rng.gen_range(0..3) {
+            0 => (sign(rng), 0),
+            1 => (0, sign(rng)),
+            _ => (sign(rng), sign(rng)),
+        };
+        // Cut the line where it would leave the floor.
+        let fits = |v: i32, d: i32| {
+            if d < 0 {
+                v
+            } else if d > 0 {
+                SIZE - 1 - v
+            } else {
+                SIZE
+            }
+        };
+        let length = length.min(fits(x1, dx)).min(fits(y1, dy));
+        format!("{},{} -> {},{}", x1, y1, x1 + dx * length, y1 + dy * length)
+    });
+    Generated::new(lines(vents))
+}
@@ -0,0 +1,8 @@
+use crate::{Generated, Rng};
+use rand::Rng as _;
+
+/// `size` lanternfish, with timers from 1 to 5.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let timers = (0..size).map(|_| rng.gen_range(1..=5).to_string()).collect::<Vec<_>>();
+    Generated::new(timers.join(",") + "\n")
+}
@@ -0,0 +1,11 @@
+use crate::{Generated, Rng};
+use rand::Rng as _;
+
+/// `size` crab positions, clustered towards the start of the range.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let range = 2000.max(size);
+    let positions = (0..size)
+        .map(|_| rng.gen_range(0..range).min(rng.gen_range(0..range)).to_string())
+        .collect::<Vec<_>>();
+    Generated::new(positions.join(",") + "\n")
+}
@@ -0,0 +1,39 @@
+use crate::{lines, Generated, Rng};
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+/// The segments lit for each digit on a correctly wired display.
+const DIGITS: [&str; 10] =
+    ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+
+/// `digit` on a display whose segment `a + i` is wired to `wires[i]`, segments in any order.
+fn scramble(rng: &mut Rng, wires: &[u8], digit: usize) -> String {
+    let mut segments =
+        DIGITS[digit].bytes().map(|s| wires[(s - b'a') as usize]).collect::<Vec<_>>();
+    segments.shuffle(rng);
+    String::from_utf8(segments).expect("segments are ASCII letters")
+}
+
+/// `size` displays, each with its own wiring and a random four-digit output.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let (mut easy_digits, mut total) = (0, 0);
+    let entries = (0..size)
+        .map(|_| {
+            let mut wires = b"abcdefg".to_vec();
+            wires.shuffle(rng);
+
+            let mut patterns = (0..10).collect::<Vec<_>>();
+            patterns.shuffle(rng);
+            let patterns = patterns.iter().map(|&d| scramble(rng, &wires, d)).collect::<Vec<_>>();
+
+            let output = (0..4).map(|_| rng.gen_range(0..10)).collect::<Vec<usize>>();
+            easy_digits += output.iter().filter(|d| [1, 4, 7, 8].contains(d)).count();
+            total += output.iter().fold(0, |value, &d| value * 10 + d);
+            let output = output.iter().map(|&d| scramble(rng, &wires, d)).collect::<Vec<_>>();
+
+            format!("{} | {}", patterns.join(" "), output.join(" "))
+        })
+        .collect::<Vec<_>>();
+
+    Generated::with_answers(lines(entries), easy_digits, total)
+}
@@ -0,0 +1,116 @@
+use crate::{map, Generated, Rng};
+use rand::seq::index;
+use rand::Rng as _;
+use std::collections::VecDeque;
+
+/// The side of the blocks that each hold the low point of one basin.
+const BLOCK: usize = 5;
+/// How far a basin reaches from its low point, so that its heights climb to at most 8.
+const REACH: usize = 8;
+
+/// A `size` by `size` heightmap of basins walled off by 9s, each flowing down to a single low
+/// point: the heights only climb with the distance from it.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(1);
+    let at = |x: usize, y: usize| y * size + x;
+    let neighbours = |i: usize| {
+        let (x, y) = (i % size, i / size);
+        [
+            (x > 0).then(|| i - 1),
+            (x + 1 < size).then(|| i + 1),
+            (y > 0).then(|| i - size),
+            (y + 1 < size).then(|| i + size),
+        ]
+        .into_iter()
+        .flatten()
+    };
+
+    // One low point per block, never next to the low point of another block.
+    let mut lows = vec![];
+    for y in (0..size).step_by(BLOCK) {
+        for x in (0..size).step_by(BLOCK) {
+            let low_x = rng.gen_range(x..(x + BLOCK - 1).min(size));
+            let low_y = rng.gen_range(y..(y + BLOCK - 1).min(size));
+            lows.push(at(low_x, low_y));
+        }
+    }
+
+    // Scattered rocks, then every other cell to the basin whose low point is nearest.
+    let mut owners = (0..size * size).map(|_| None).collect::<Vec<_>>();
+    let mut rocks = (0..size * size).map(|_| rng.gen_ratio(1, 10)).collect::<Vec<_>>();
+    let mut queue = VecDeque::new();
+    for (basin, &low) in lows.iter().enumerate() {
+        rocks[low] = false;
+        owners[low] = Some(basin);
+        queue.push_back(low);
+    }
+    while let Some(cell) = queue.pop_front() {
+        for neighbour in neighbours(cell) {
+            if owners[neighbour].is_none() && !rocks[neighbour] {
+                owners[neighbour] = owners[cell];
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    // Wherever two basins meet, one of the cells becomes a wall, never a low point.
+    for cell in 0..size * size {
+        let Some(basin) = owners[cell] else { continue };
+        if lows[basin] == cell {
+            continue;
+        }
+        if neighbours(cell).any(|neighbour| {
+            owners[neighbour]
+                .is_some_and(|other| other != basin && (other < basin || lows[other] == neighbour))
+        }) {
+            owners[cell] = None;
+        }
+    }
+
+    // The walls may have cut basins short: keep what is still within reach of its low point.
+    let mut distances = (0..size * size).map(|_| None).collect::<Vec<_>>();
+    let mut sizes = vec![0; lows.len()];
+    let mut depths = vec![0; lows.len()];
+    for (basin, &low) in lows.iter().enumerate() {
+        distances[low] = Some(0);
+        queue.push_back(low);
+        while let Some(cell) = queue.pop_front() {
+            sizes[basin] += 1;
+            let distance = distances[cell].expect("queued cells have a distance");
+            depths[basin] = distance;
+            if distance == REACH {
+                continue;
+            }
+            for neighbour in neighbours(cell) {
+                if owners[neighbour] == Some(basin) && distances[neighbour].is_none() {
+                    distances[neighbour] = Some(distance + 1);
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+    }
+
+    // Each basin climbs through its own heights, one for each distance from its low point.
+    let heights = depths
+        .iter()
+        .map(|&depth| {
+            let mut heights = index::sample(rng, REACH + 1, depth + 1).into_vec();
+            heights.sort_unstable();
+            heights
+        })
+        .collect::<Vec<_>>();
+    let input = map(size, size, |x, y| {
+        let cell = at(x, y);
+        match (owners[cell], distances[cell]) {
+            (Some(basin), Some(distance)) => char::from(b'0' + heights[basin][distance] as u8),
+            _ => '9',
+        }
+    });
+
+    let risks = heights.iter().map(|heights| heights[0] + 1).sum::<usize>();
+    sizes.sort_unstable();
+    match sizes.len() {
+        0..=2 => Generated { part1: Some(risks.into()), ..Generated::new(input) },
+        n => Generated::with_answers(input, risks, sizes[n - 3..].iter().product::<usize>()),
+    }
+}
@@ -0,0 +1,134 @@
+//! Random puzzle-shaped inputs, to stress-test and benchmark the solvers.
+//!
+//! Every generator takes a seed and a size and is deterministic: the same seed and size always
+//! give the same input. When the input is built from a known solution, such as the scanners of
+//! day 19 placed at chosen positions, the expected answers come with it.
+
+use common::Answer;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+mod day1;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day2;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+
+type Rng = ChaCha8Rng;
+
+/// A generated input, with the answers of both parts when the generator knows them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Generated {
+    fn new(input: String) -> Self {
+        Self { input, part1: None, part2: None }
+    }
+
+    fn with_answers(input: String, part1: impl Into<Answer>, part2: impl Into<Answer>) -> Self {
+        Self { input, part1: Some(part1.into()), part2: Some(part2.into()) }
+    }
+
+    pub fn answer(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Generates an input for `day`, whose `size` is described by the generator of that day.
+///
+/// Returns `None` if `day` is not a day of the calendar.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<Generated> {
+    let rng = &mut Rng::seed_from_u64(seed);
+    let generated = match day {
+        1 => day1::generate(rng, size),
+        2 => day2::generate(rng, size),
+        3 => day3::generate(rng, size),
+        4 => day4::generate(rng, size),
+        5 => day5::generate(rng, size),
+        6 => day6::generate(rng, size),
+        7 => day7::generate(rng, size),
+        8 => day8::generate(rng, size),
+        9 => day9::generate(rng, size),
+        10 => day10::generate(rng, size),
+        11 => day11::generate(rng, size),
+        12 => day12::generate(rng, size),
+        13 => day13::generate(rng, size),
+        14 => day14::generate(rng, size),
+        15 => day15::generate(rng, size),
+        16 => day16::generate(rng, size),
+        17 => day17::generate(rng, size),
+        18 => day18::generate(rng, size),
+        19 => day19::generate(rng, size),
+        20 => day20::generate(rng, size),
+        21 => day21::generate(rng, size),
+        22 => day22::generate(rng, size),
+        23 => day23::generate(rng, size),
+        24 => day24::generate(rng, size),
+        25 => day25::generate(rng, size),
+        _ => return None,
+    };
+    Some(generated)
+}
+
+/// About the size of the actual puzzle input of `day`, as understood by its generator.
+pub fn puzzle_size(day: u8) -> usize {
+    match day {
+        1 => 2000,
+        2 | 3 | 7 => 1000,
+        5 => 500,
+        6 => 300,
+        8 => 200,
+        11 => 10,
+        12 => 6,
+        13 => 800,
+        14 => 20,
+        16 => 60,
+        19 => 30,
+        22 => 420,
+        25 => 139,
+        _ => 100,
+    }
+}
+
+/// One line per item.
+fn lines<T: ToString>(items: impl IntoIterator<Item = T>) -> String {
+    items.into_iter().map(|item| item.to_string() + "\n").collect()
+}
+
+/// A `width` by `height` map, one character per cell.
+fn map(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> char) -> String {
+    let mut output = String::with_capacity((width + 1) * height);
+    for y in 0..height {
+        output.extend((0..width).map(|x| cell(x, y)));
+        output.push('\n');
+    }
+    output
+}
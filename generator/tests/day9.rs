@@ -0,0 +1,66 @@
+use generator::generate;
+
+type Heightmap = Vec<Vec<u8>>;
+
+fn neighbours(map: &Heightmap, (x, y): (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+    let (width, height) = (map[0].len(), map.len());
+    [
+        (x > 0).then(|| (x - 1, y)),
+        (x + 1 < width).then(|| (x + 1, y)),
+        (y > 0).then(|| (x, y - 1)),
+        (y + 1 < height).then(|| (x, y + 1)),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// The low points of each region of cells that 9s wall off.
+fn low_points_per_basin(map: &Heightmap) -> Vec<usize> {
+    let mut seen = map.iter().map(|row| vec![false; row.len()]).collect::<Vec<_>>();
+    let mut basins = vec![];
+    for y in 0..map.len() {
+        for x in 0..map[y].len() {
+            if seen[y][x] || map[y][x] == 9 {
+                continue;
+            }
+            seen[y][x] = true;
+            let mut stack = vec![(x, y)];
+            let mut low_points = 0;
+            while let Some(cell @ (x, y)) = stack.pop() {
+                if neighbours(map, cell).all(|(nx, ny)| map[y][x] < map[ny][nx]) {
+                    low_points += 1;
+                }
+                for (nx, ny) in neighbours(map, cell) {
+                    if !seen[ny][nx] && map[ny][nx] != 9 {
+                        seen[ny][nx] = true;
+                        stack.push((nx, ny));
+                    }
+                }
+            }
+            basins.push(low_points);
+        }
+    }
+    basins
+}
+
+#[test]
+fn every_basin_has_one_low_point() {
+    for seed in 0..20 {
+        for size in [1, 4, 10, 37, 100] {
+            let generated = generate(9, seed, size).unwrap();
+            let map = generated
+                .input
+                .lines()
+                .map(|line| line.bytes().map(|b| b - b'0').collect())
+                .collect::<Heightmap>();
+            let basins = low_points_per_basin(&map);
+            assert!(!basins.is_empty(), "seed {} size {}", seed, size);
+            assert!(
+                basins.iter().all(|&low_points| low_points == 1),
+                "seed {} size {}",
+                seed,
+                size
+            );
+        }
+    }
+}
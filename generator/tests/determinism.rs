@@ -0,0 +1,21 @@
+use generator::generate;
+
+#[test]
+fn same_seed_same_input() {
+    for day in 1..=25 {
+        assert_eq!(generate(day, 42, 20), generate(day, 42, 20), "day {}", day);
+    }
+}
+
+#[test]
+fn seeds_give_different_inputs() {
+    for day in 1..=25 {
+        assert_ne!(generate(day, 1, 20), generate(day, 2, 20), "day {}", day);
+    }
+}
+
+#[test]
+fn only_calendar_days() {
+    assert_eq!(generate(0, 0, 10), None);
+    assert_eq!(generate(26, 0, 10), None);
+}
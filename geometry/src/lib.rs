@@ -0,0 +1,276 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+/// A point or a displacement on a plane.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A point or a displacement in space.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+macro_rules! vector {
+    ($name:ident, $($field:ident),+) => {
+        impl $name {
+            pub const ZERO: Self = Self { $($field: 0),+ };
+
+            pub const fn new($($field: i64),+) -> Self {
+                Self { $($field),+ }
+            }
+
+            /// The sign of each component, which steps along a horizontal, vertical or
+            /// diagonal line.
+            pub fn signum(self) -> Self {
+                Self { $($field: self.$field.signum()),+ }
+            }
+
+            pub fn abs(self) -> Self {
+                Self { $($field: self.$field.abs()),+ }
+            }
+
+            /// The sum of the absolute values of the components.
+            pub fn length(self) -> i64 {
+                0 $(+ self.$field.abs())+
+            }
+
+            pub fn manhattan(self, other: Self) -> i64 {
+                (self - other).length()
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl Mul<i64> for $name {
+            type Output = Self;
+
+            fn mul(self, factor: i64) -> Self {
+                Self { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+vector!(Vec2, x, y);
+vector!(Vec3, x, y, z);
+
+impl Display for Vec2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+impl Display for Vec3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{},{})", self.x, self.y, self.z)
+    }
+}
+
+/// Components by axis: 0 for x, 1 for y and 2 for z.
+impl Index<usize> for Vec3 {
+    type Output = i64;
+
+    fn index(&self, axis: usize) -> &i64 {
+        match axis {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("axis {} of a 3D vector", axis),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vec3 {
+    fn index_mut(&mut self, axis: usize) -> &mut i64 {
+        match axis {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("axis {} of a 3D vector", axis),
+        }
+    }
+}
+
+/// An axis-aligned rectangle, both corners included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rect {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+/// An axis-aligned box, both corners included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cuboid {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+macro_rules! aligned_box {
+    ($name:ident, $vector:ident, $($field:ident),+) => {
+        impl $name {
+            /// The box between two corners, which must not be above `max` on any axis.
+            pub fn new(min: $vector, max: $vector) -> Self {
+                debug_assert!(true $(&& min.$field <= max.$field)+, "{} is above {}", min, max);
+                Self { min, max }
+            }
+
+            pub fn contains(&self, point: $vector) -> bool {
+                true $(&& (self.min.$field..=self.max.$field).contains(&point.$field))+
+            }
+
+            /// Whether `other` is entirely inside of this box.
+            pub fn encloses(&self, other: &Self) -> bool {
+                self.contains(other.min) && self.contains(other.max)
+            }
+
+            pub fn intersects(&self, other: &Self) -> bool {
+                true $(&& self.min.$field <= other.max.$field && other.min.$field <= self.max.$field)+
+            }
+
+            pub fn intersection(&self, other: &Self) -> Option<Self> {
+                self.intersects(other).then(|| Self {
+                    min: $vector { $($field: self.min.$field.max(other.min.$field)),+ },
+                    max: $vector { $($field: self.max.$field.min(other.max.$field)),+ },
+                })
+            }
+
+            /// How many integer points the box holds.
+            pub fn volume(&self) -> u64 {
+                1 $(* (self.max.$field - self.min.$field + 1) as u64)+
+            }
+        }
+    };
+}
+
+aligned_box!(Rect, Vec2, x, y);
+aligned_box!(Cuboid, Vec3, x, y, z);
+
+impl Cuboid {
+    /// The box split in two along `axis` right before `at`, or whole if it does not cross it.
+    pub fn split(self, axis: usize, at: i64) -> (Option<Self>, Option<Self>) {
+        if at <= self.min[axis] {
+            (None, Some(self))
+        } else if at > self.max[axis] {
+            (Some(self), None)
+        } else {
+            let (mut below, mut above) = (self, self);
+            below.max[axis] = at - 1;
+            above.min[axis] = at;
+            (Some(below), Some(above))
+        }
+    }
+}
+
+/// One of the 24 ways to turn a cube onto itself: each axis goes to an axis, possibly reversed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rotation {
+    /// The component of the original vector that ends up on each axis.
+    axes: [usize; 3],
+    /// Whether it ends up there reversed, as -1, or not, as 1.
+    signs: [i64; 3],
+}
+
+impl Rotation {
+    pub const IDENTITY: Self = Self { axes: [0, 1, 2], signs: [1, 1, 1] };
+
+    /// Every rotation, the identity first.
+    pub fn all() -> [Self; 24] {
+        let permutations = [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+        let mut rotations = [Self::IDENTITY; 24];
+        let mut count = 0;
+        for axes in permutations {
+            // Swapping two axes is a reflection, which an odd number of reversals undoes.
+            let parity = if axes.iter().enumerate().filter(|&(i, &axis)| i != axis).count() == 2 {
+                -1
+            } else {
+                1
+            };
+            for reversals in 0..8 {
+                let signs = [0, 1, 2].map(|i| if reversals >> i & 1 == 1 { -1 } else { 1 });
+                if signs.iter().product::<i64>() == parity {
+                    rotations[count] = Self { axes, signs };
+                    count += 1;
+                }
+            }
+        }
+        rotations
+    }
+
+    /// The rotation turning back what this one turns.
+    pub fn inverse(self) -> Self {
+        let mut inverse = Self::IDENTITY;
+        for (i, &axis) in self.axes.iter().enumerate() {
+            inverse.axes[axis] = i;
+            inverse.signs[axis] = self.signs[i];
+        }
+        inverse
+    }
+}
+
+impl Mul<Vec3> for Rotation {
+    type Output = Vec3;
+
+    fn mul(self, v: Vec3) -> Vec3 {
+        Vec3::new(
+            self.signs[0] * v[self.axes[0]],
+            self.signs[1] * v[self.axes[1]],
+            self.signs[2] * v[self.axes[2]],
+        )
+    }
+}
+
+/// Composition: `(a * b) * v` is `a * (b * v)`.
+impl Mul for Rotation {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            axes: self.axes.map(|axis| other.axes[axis]),
+            signs: [0, 1, 2].map(|i| self.signs[i] * other.signs[self.axes[i]]),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use geometry::{Cuboid, Rect, Rotation, Vec2, Vec3};
+use std::collections::HashSet;
+
+#[test]
+fn adds_and_measures_vectors() {
+    let (a, b) = (Vec3::new(1, -2, 3), Vec3::new(-4, 5, 6));
+    assert_eq!(a + b, Vec3::new(-3, 3, 9));
+    assert_eq!(a - b, Vec3::new(5, -7, -3));
+    assert_eq!(-a * 2, Vec3::new(-2, 4, -6));
+    assert_eq!(a.manhattan(b), 15);
+    assert_eq!(Vec2::new(-3, 7).signum(), Vec2::new(-1, 1));
+    assert_eq!(Vec2::new(-3, 7).to_string(), "(-3,7)");
+}
+
+#[test]
+fn intersects_boxes() {
+    let a = Cuboid::new(Vec3::new(10, 10, 10), Vec3::new(12, 12, 12));
+    let b = Cuboid::new(Vec3::new(11, 11, 11), Vec3::new(13, 13, 13));
+    assert_eq!(a.volume(), 27);
+    assert_eq!(a.intersection(&b), Some(Cuboid::new(Vec3::new(11, 11, 11), Vec3::new(12, 12, 12))));
+    assert!(!a.encloses(&b));
+
+    let rect = Rect::new(Vec2::new(20, -10), Vec2::new(30, -5));
+    assert!(rect.contains(Vec2::new(28, -7)));
+    assert!(!rect.contains(Vec2::new(28, -4)));
+    assert_eq!(rect.intersection(&Rect::new(Vec2::new(31, -10), Vec2::new(40, 0))), None);
+}
+
+#[test]
+fn splits_boxes() {
+    let cuboid = Cuboid::new(Vec3::new(0, 0, 0), Vec3::new(3, 1, 1));
+    let (below, above) = cuboid.split(0, 2);
+    assert_eq!(below, Some(Cuboid::new(Vec3::new(0, 0, 0), Vec3::new(1, 1, 1))));
+    assert_eq!(above, Some(Cuboid::new(Vec3::new(2, 0, 0), Vec3::new(3, 1, 1))));
+    assert_eq!(cuboid.split(0, 0), (None, Some(cuboid)));
+    assert_eq!(cuboid.split(1, 2), (Some(cuboid), None));
+}
+
+#[test]
+fn rotations_form_a_group() {
+    let rotations = Rotation::all();
+    assert_eq!(rotations[0], Rotation::IDENTITY);
+    assert_eq!(rotations.iter().collect::<HashSet<_>>().len(), 24);
+
+    let v = Vec3::new(1, 2, 3);
+    for a in rotations {
+        assert_eq!(a * a.inverse(), Rotation::IDENTITY);
+        assert_eq!(a.inverse() * (a * v), v);
+        for b in rotations {
+            assert!(rotations.contains(&(a * b)));
+            assert_eq!((a * b) * v, a * (b * v));
+        }
+    }
+}
+
+#[test]
+fn rotations_keep_handedness() {
+    let (x, y, z) = (Vec3::new(1, 0, 0), Vec3::new(0, 1, 0), Vec3::new(0, 0, 1));
+    let cross = |a: Vec3, b: Vec3| {
+        Vec3::new(a.y * b.z - a.z * b.y, a.z * b.x - a.x * b.z, a.x * b.y - a.y * b.x)
+    };
+    for rotation in Rotation::all() {
+        assert_eq!(cross(rotation * x, rotation * y), rotation * z);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::Grid;
+use std::fmt::Write;
+
+pub type Rgb = [u8; 3];
+
+/// How one cell of a simulation looks: a character for text, a colour for the terminal and images.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Pixel {
+    pub symbol: char,
+    pub colour: Rgb,
+}
+
+impl Pixel {
+    pub const fn new(symbol: char, colour: Rgb) -> Self {
+        Self { symbol, colour }
+    }
+}
+
+/// One state of a simulation, as yielded by the `frames()` of the days.
+pub type Frame = Grid<Pixel>;
+
+impl Grid<Pixel> {
+    /// Draws the frame with ANSI true colour escapes, one line per row.
+    pub fn to_ansi(&self) -> String {
+        let mut output = String::new();
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let Pixel { symbol, colour: [r, g, b] } = self[(x, y)];
+                write!(output, "\x1b[38;2;{};{};{}m{}", r, g, b, symbol)
+                    .expect("writing to a string");
+            }
+            output.push_str("\x1b[0m\n");
+        }
+        output
+    }
+
+    /// Draws the frame as plain text.
+    pub fn to_text(&self) -> String {
+        self.render(|pixel| pixel.symbol)
+    }
+
+    /// Encodes the frame as a binary PPM image, each cell a square of `scale` pixels.
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let scale = scale.max(1);
+        let (width, height) = (self.width() * scale, self.height() * scale);
+        let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        image.reserve(width * height * 3);
+        for y in 0..height {
+            for x in 0..width {
+                image.extend(self[(x / scale, y / scale)].colour);
+            }
+        }
+        image
+    }
+}
+
+/// Frames drawing a map of characters, such as the `Display` of a game state, each character
+/// coloured by `colour`. Short lines are padded with spaces.
+pub fn from_text(text: &str, mut colour: impl FnMut(char) -> Rgb) -> Frame {
+    let width = text.lines().map(|line| line.chars().count()).max().unwrap_or(0);
+    let height = text.lines().count();
+    let rows = text.lines().map(|line| line.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
+    Grid::from_fn(width, height, |(x, y)| {
+        let symbol = rows[y].get(x).copied().unwrap_or(' ');
+        Pixel::new(symbol, colour(symbol))
+    })
+}
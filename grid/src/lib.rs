@@ -0,0 +1,194 @@
+pub mod frame;
+#[cfg(feature = "serde")]
+mod serde;
+
+use common::{input, ParseError, Source};
+pub use frame::{Frame, Pixel};
+use std::ops::{Index, IndexMut};
+
+/// `(x, y)`, with `y` growing downwards.
+pub type Position = (usize, usize);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// Up, down, left and right.
+    Orthogonal,
+    /// The four corners.
+    Diagonal,
+    /// Orthogonal and diagonal neighbours together.
+    All,
+}
+
+impl Neighbourhood {
+    pub fn offsets(self) -> &'static [(isize, isize)] {
+        const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+        const DIAGONAL: [(isize, isize); 4] = [(-1, -1), (1, -1), (-1, 1), (1, 1)];
+        const ALL: [(isize, isize); 8] =
+            [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+        match self {
+            Neighbourhood::Orthogonal => &ORTHOGONAL,
+            Neighbourhood::Diagonal => &DIAGONAL,
+            Neighbourhood::All => &ALL,
+        }
+    }
+}
+
+/// A rectangular map stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { cells: vec![fill; width * height], width, height }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(&mut f).collect();
+        Self { cells, width, height }
+    }
+
+    /// Builds a grid from cells listed row by row, if they fill whole rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Option<Self> {
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            return None;
+        }
+        let height = cells.len() / width;
+        Some(Self { cells, width, height })
+    }
+
+    /// Parses one cell per character, rejecting empty input and lines of different lengths.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        Self::parse_section(Source::new(input), input, expected, cell)
+    }
+
+    /// Like [`Grid::parse`], for a `section` borrowed from the input of `source`.
+    pub fn parse_section(
+        source: Source<'_>,
+        section: &str,
+        expected: &str,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let (width, cells) = input::char_grid(source, section, expected, cell)?;
+        Ok(Self::from_vec(width, cells).expect("the cells fill whole rows"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Position) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (x, y): Position) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// Looks up signed coordinates, anything outside of the grid being `default`.
+    pub fn get_or<'a>(&'a self, x: isize, y: isize, default: &'a T) -> &'a T {
+        match (usize::try_from(x), usize::try_from(y)) {
+            (Ok(x), Ok(y)) => self.get((x, y)).unwrap_or(default),
+            _ => default,
+        }
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn cells_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(f).collect(), width: self.width, height: self.height }
+    }
+
+    /// Position at `offset` from `position`, if it is still in the grid.
+    pub fn offset(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+        Some((x, y))
+    }
+
+    /// Position at `offset` from `position`, wrapping around the edges as on a torus.
+    pub fn wrapping_offset(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Position {
+        let wrap =
+            |v: usize, d: isize, size: usize| (v as isize + d).rem_euclid(size as isize) as usize;
+        (wrap(x, dx, self.width), wrap(y, dy, self.height))
+    }
+
+    pub fn neighbours(
+        &self,
+        position: Position,
+        neighbourhood: Neighbourhood,
+    ) -> impl Iterator<Item = Position> + '_ {
+        neighbourhood.offsets().iter().filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    pub fn wrapping_neighbours(
+        &self,
+        position: Position,
+        neighbourhood: Neighbourhood,
+    ) -> impl Iterator<Item = Position> + '_ {
+        neighbourhood.offsets().iter().map(move |&offset| self.wrapping_offset(position, offset))
+    }
+
+    /// Draws the grid one character per cell, one line per row.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for row in self.cells.chunks(self.width.max(1)) {
+            output.extend(row.iter().map(&mut f));
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a map of single digits, such as heights or risk levels.
+    pub fn parse_digits(input: &str) -> Result<Self, ParseError> {
+        Self::parse(input, "a digit", |c| c.to_digit(10).map(|digit| digit as u8))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Position) -> &Self::Output {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside of the grid", x, y);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Position) -> &mut Self::Output {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside of the grid", x, y);
+        &mut self.cells[y * self.width + x]
+    }
+}
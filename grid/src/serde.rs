@@ -0,0 +1,23 @@
+//! Grids written as lists of rows.
+
+use crate::Grid;
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, Serializer};
+
+impl<T: Serialize> Serialize for Grid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.cells.chunks(self.width))
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Grid<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows = Vec::<Vec<T>>::deserialize(deserializer)?;
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return Err(D::Error::custom("rows of different lengths"));
+        }
+        Grid::from_vec(width, rows.into_iter().flatten().collect())
+            .ok_or_else(|| D::Error::custom("no cells"))
+    }
+}
@@ -0,0 +1,104 @@
+use grid::{Grid, Neighbourhood};
+use std::collections::HashSet;
+
+/// A 4 by 3 grid numbering its cells row by row.
+fn numbered() -> Grid<u8> {
+    Grid::from_fn(4, 3, |(x, y)| (y * 4 + x) as u8)
+}
+
+fn neighbours(grid: &Grid<u8>, position: (usize, usize), neighbourhood: Neighbourhood) -> Vec<u8> {
+    let mut cells = grid.neighbours(position, neighbourhood).map(|p| grid[p]).collect::<Vec<_>>();
+    cells.sort_unstable();
+    cells
+}
+
+#[test]
+fn neighbours_stop_at_the_edges() {
+    let grid = numbered();
+    assert_eq!(neighbours(&grid, (1, 1), Neighbourhood::Orthogonal), [1, 4, 6, 9]);
+    assert_eq!(neighbours(&grid, (1, 1), Neighbourhood::Diagonal), [0, 2, 8, 10]);
+    assert_eq!(neighbours(&grid, (1, 1), Neighbourhood::All), [0, 1, 2, 4, 6, 8, 9, 10]);
+
+    // Corners.
+    assert_eq!(neighbours(&grid, (0, 0), Neighbourhood::Orthogonal), [1, 4]);
+    assert_eq!(neighbours(&grid, (0, 0), Neighbourhood::All), [1, 4, 5]);
+    assert_eq!(neighbours(&grid, (3, 2), Neighbourhood::Diagonal), [6]);
+    assert_eq!(neighbours(&grid, (3, 2), Neighbourhood::All), [6, 7, 10]);
+    // Edges.
+    assert_eq!(neighbours(&grid, (2, 0), Neighbourhood::Orthogonal), [1, 3, 6]);
+    assert_eq!(neighbours(&grid, (0, 1), Neighbourhood::All), [0, 1, 5, 8, 9]);
+
+    let single = Grid::new(1, 1, 0u8);
+    assert_eq!(single.neighbours((0, 0), Neighbourhood::All).count(), 0);
+}
+
+#[test]
+fn offsets_wrap_around_as_on_a_torus() {
+    let grid = numbered();
+    assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+    assert_eq!(grid.offset((3, 2), (0, 1)), None);
+    assert_eq!(grid.offset((3, 2), (-3, -2)), Some((0, 0)));
+
+    assert_eq!(grid.wrapping_offset((0, 0), (-1, -1)), (3, 2));
+    assert_eq!(grid.wrapping_offset((3, 2), (1, 1)), (0, 0));
+    assert_eq!(grid.wrapping_offset((1, 1), (-9, 7)), (0, 2));
+
+    let wrapped = grid.wrapping_neighbours((0, 0), Neighbourhood::All).collect::<HashSet<_>>();
+    assert_eq!(wrapped.len(), 8);
+    assert!(wrapped.contains(&(3, 2)) && wrapped.contains(&(1, 1)));
+}
+
+#[test]
+fn looks_up_outside_of_the_grid() {
+    let grid = numbered();
+    assert_eq!(grid.get((3, 2)), Some(&11));
+    assert_eq!(grid.get((4, 0)), None);
+    assert_eq!(grid.get((0, 3)), None);
+
+    assert_eq!(*grid.get_or(2, 1, &99), 6);
+    for (x, y) in [(-1, 0), (0, -1), (4, 0), (0, 3), (isize::MIN, isize::MAX)] {
+        assert_eq!(*grid.get_or(x, y, &99), 99, "({}, {})", x, y);
+    }
+}
+
+#[test]
+#[should_panic(expected = "(4, 0) is outside of the grid")]
+fn indexing_outside_of_the_grid_panics() {
+    let _ = numbered()[(4, 0)];
+}
+
+#[test]
+fn builds_from_whole_rows_only() {
+    assert_eq!(Grid::from_vec(2, vec![1, 2, 3, 4]).map(|grid| grid.height()), Some(2));
+    assert_eq!(Grid::from_vec(3, vec![1, 2, 3, 4]), None);
+    assert_eq!(Grid::<u8>::from_vec(0, vec![]), None);
+}
+
+#[test]
+fn renders_one_line_per_row() {
+    let grid = numbered();
+    assert_eq!(grid.render(|&cell| if cell % 2 == 0 { '#' } else { '.' }), "#.#.\n#.#.\n#.#.\n");
+    assert_eq!(Grid::new(0, 0, 0u8).render(|_| '#'), "");
+}
+
+#[test]
+fn parses_digits() {
+    let grid = Grid::parse_digits("012\n345\n").unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(2, 1)], 5);
+    assert_eq!(grid.render(|&digit| char::from(b'0' + digit)), "012\n345\n");
+}
+
+#[test]
+fn rejects_malformed_maps() {
+    let error = Grid::parse_digits("012\n3a5\n").unwrap_err();
+    assert_eq!((error.line, error.column, error.expected.as_str()), (2, 2, "a digit"));
+
+    let error = Grid::parse_digits("012\n34\n").unwrap_err();
+    assert_eq!((error.line, error.expected.as_str()), (2, "a line of 3 cells"));
+    let error = Grid::parse_digits("012\n3456\n").unwrap_err();
+    assert_eq!((error.line, error.column, error.expected.as_str()), (2, 4, "end of line"));
+
+    assert!(Grid::parse_digits("").is_err());
+    assert!(Grid::parse_digits("\n012\n").is_err());
+}
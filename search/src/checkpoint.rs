@@ -0,0 +1,216 @@
+use crate::{Node, Outcome, Search, State, Stats, BATCH};
+use common::Error;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::cmp::Reverse;
+use std::collections::{hash_map, BinaryHeap, HashMap};
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+/// A state that a search can be saved with, to be picked up later.
+pub trait Checkpoint: State<Cost: Serialize + DeserializeOwned + Display> {
+    /// What to save of a state, leaving out what it borrows from the puzzle.
+    type Saved: Serialize + DeserializeOwned + PartialEq;
+
+    fn save(&self) -> Self::Saved;
+
+    /// The state `saved` from a search like the one of `self`, borrowing from the same puzzle.
+    fn restore(&self, saved: Self::Saved) -> Self;
+
+    /// How far along the state is, in a few words for progress reports.
+    fn describe(&self) -> String;
+}
+
+/// A search as written to a checkpoint.
+#[derive(Serialize, Deserialize)]
+struct Saved<T, C> {
+    /// Tells apart the puzzles that searches with the same start are run on.
+    puzzle: u64,
+    /// Every state reached, with its cost and the index of the state it was reached from.
+    nodes: Vec<(T, C, Option<usize>)>,
+    /// The estimate, cost and index of the states waiting to be expanded.
+    frontier: Vec<(C, C, usize)>,
+    stats: Stats,
+}
+
+impl<S: Checkpoint> Search<S> {
+    /// Writes the search, for `load` to pick up on the same `puzzle`.
+    pub fn save(&self, puzzle: u64, writer: impl Write) -> io::Result<()> {
+        let saved = Saved {
+            puzzle,
+            nodes: self
+                .nodes
+                .iter()
+                .map(|node| (node.state.save(), node.cost, node.parent))
+                .collect(),
+            frontier: self
+                .frontier
+                .iter()
+                .map(|&(Reverse(estimate), cost, Reverse(index))| (estimate, cost, index))
+                .collect(),
+            stats: self.stats,
+        };
+        serde_json::to_writer(writer, &saved)?;
+        Ok(())
+    }
+
+    /// The search written by `save`, which must have been on `puzzle` and started from `start`.
+    pub fn load(reader: impl Read, puzzle: u64, start: &S) -> io::Result<Self> {
+        let saved: Saved<S::Saved, S::Cost> = serde_json::from_reader(reader)?;
+        if saved.puzzle != puzzle
+            || saved.nodes.first().is_none_or(|(state, ..)| *state != start.save())
+        {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "it is of another search"));
+        }
+        if saved.frontier.iter().any(|&(.., index)| index >= saved.nodes.len()) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "its frontier is corrupt"));
+        }
+        // A state is reached from one reached before it, which keeps paths from looping.
+        if saved
+            .nodes
+            .iter()
+            .enumerate()
+            .any(|(index, (.., parent))| parent.is_some_and(|p| p >= index))
+        {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "its nodes are corrupt"));
+        }
+
+        let nodes = saved
+            .nodes
+            .into_iter()
+            .map(|(state, cost, parent)| Node { state: start.restore(state), cost, parent })
+            .collect::<Vec<_>>();
+        let mut best = HashMap::new();
+        for node in &nodes {
+            match best.entry(node.state.key()) {
+                hash_map::Entry::Occupied(mut entry) if *entry.get() > node.cost => {
+                    *entry.get_mut() = node.cost
+                }
+                hash_map::Entry::Occupied(_) => {}
+                hash_map::Entry::Vacant(entry) => {
+                    entry.insert(node.cost);
+                }
+            }
+        }
+        let frontier = saved
+            .frontier
+            .into_iter()
+            .map(|(estimate, cost, index)| (Reverse(estimate), cost, Reverse(index)))
+            .collect::<BinaryHeap<_>>();
+
+        Ok(Self { nodes, best, frontier, stats: saved.stats })
+    }
+}
+
+/// How the searches started by [`run`] report progress and save checkpoints.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// How often to print progress to the standard error, if at all.
+    pub progress: Option<Duration>,
+    /// Directory to save checkpoints to, if any.
+    pub directory: Option<PathBuf>,
+    /// How often to save a checkpoint.
+    pub every: Duration,
+    /// Whether to pick up a search from its checkpoint when there is one.
+    pub resume: bool,
+}
+
+static SETTINGS: RwLock<Settings> = RwLock::new(Settings {
+    progress: None,
+    directory: None,
+    every: Duration::from_secs(60),
+    resume: false,
+});
+
+pub fn configure(settings: Settings) {
+    *SETTINGS.write().unwrap() = settings;
+}
+
+/// Like [`search`](crate::search) from `start`, but reporting progress and saving checkpoints to
+/// `<name>.json` as configured. Only a search on the same `puzzle`, such as the same program,
+/// resumes from them.
+pub fn run<S: Checkpoint>(
+    name: &str,
+    puzzle: &impl Serialize,
+    start: S,
+) -> common::Result<Outcome<S>> {
+    let _span = tracing::debug_span!("search", name).entered();
+    let settings = SETTINGS.read().unwrap().clone();
+    let puzzle = fingerprint(puzzle)?;
+    let path =
+        settings.directory.as_ref().map(|directory| directory.join(format!("{}.json", name)));
+
+    let mut search = match &path {
+        Some(path) if settings.resume && path.exists() => {
+            let reader = File::open(path).map(BufReader::new);
+            let search =
+                reader.and_then(|reader| Search::load(reader, puzzle, &start)).map_err(|e| {
+                    Error::Checkpoint(format!("cannot resume from {}: {}", path.display(), e))
+                })?;
+            eprintln!("{}: resumed after {} states expanded", name, search.stats.expanded);
+            search
+        }
+        _ => Search::new([start]),
+    };
+
+    let (mut reported, mut saved) = (Instant::now(), Instant::now());
+    loop {
+        common::cancel::check()?;
+        if let Some(outcome) = search.advance(BATCH) {
+            return Ok(outcome);
+        }
+        if settings.progress.is_some_and(|every| reported.elapsed() >= every) {
+            report(name, &search);
+            reported = Instant::now();
+        }
+        if let Some(path) = path.as_ref().filter(|_| saved.elapsed() >= settings.every) {
+            // The search goes on without its checkpoint rather than losing the work done.
+            if let Err(e) = save(&search, puzzle, path) {
+                eprintln!("{}: cannot save a checkpoint to {}: {}", name, path.display(), e);
+            }
+            saved = Instant::now();
+        }
+    }
+}
+
+/// Tells apart puzzles by their serialized form, which unlike `Hash` stays the same from one
+/// build to the next.
+fn fingerprint(puzzle: &impl Serialize) -> common::Result<u64> {
+    let serialized = serde_json::to_vec(puzzle)
+        .map_err(|e| Error::Checkpoint(format!("cannot serialize the puzzle: {}", e)))?;
+    let digest = Sha256::digest(serialized);
+    Ok(u64::from_le_bytes(digest[..8].try_into().unwrap()))
+}
+
+fn report<S: Checkpoint>(name: &str, search: &Search<S>) {
+    let stats = search.stats;
+    let next = search.peek().map_or_else(String::new, |(state, cost)| {
+        format!(", next at cost {} with {}", cost, state.describe())
+    });
+    eprintln!(
+        "{}: {} expanded, {} in the frontier, {} stale{}",
+        name,
+        stats.expanded,
+        search.frontier_len(),
+        stats.stale,
+        next
+    );
+}
+
+/// Writes the checkpoint next to `path` first, so that an interruption leaves the previous one.
+fn save<S: Checkpoint>(search: &Search<S>, puzzle: u64, path: &Path) -> io::Result<()> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+    let partial = path.with_extension("json.partial");
+    let mut writer = BufWriter::new(File::create(&partial)?);
+    search.save(puzzle, &mut writer)?;
+    writer.flush()?;
+    drop(writer);
+    fs::rename(&partial, path)
+}
@@ -0,0 +1,196 @@
+pub mod checkpoint;
+
+pub use checkpoint::Checkpoint;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::hash_map;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A node of a search graph, such as a position on a map or the state of a game.
+pub trait State: Sized {
+    /// Identifies states that are the same node, however they were reached.
+    type Key: Hash + Eq;
+    /// The cost of a path. Steps must never cost less than `Cost::default()`.
+    type Cost: Copy + Ord + Default + Add<Output = Self::Cost>;
+
+    fn key(&self) -> Self::Key;
+
+    /// The states one step away, with the cost of getting there.
+    fn successors(&self) -> Vec<(Self, Self::Cost)>;
+
+    fn is_goal(&self) -> bool;
+
+    /// A lower bound of the cost from this state to a goal, which turns the search into A*.
+    ///
+    /// It must not overestimate, nor drop along a step by more than the step costs.
+    fn heuristic(&self) -> Self::Cost {
+        Self::Cost::default()
+    }
+}
+
+/// How much work a search did.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    /// States whose successors were looked at.
+    pub expanded: usize,
+    /// States queued, counting the starts.
+    pub queued: usize,
+    /// Queued states dropped because a cheaper path to them was found in the meantime.
+    pub stale: usize,
+    /// Largest number of states waiting to be expanded at once.
+    pub max_frontier: usize,
+}
+
+impl Stats {
+    fn trace(&self, found: bool) {
+        let Stats { expanded, queued, stale, max_frontier } = *self;
+        tracing::debug!(found, expanded, queued, stale, max_frontier, "search over");
+    }
+}
+
+/// The cheapest path found to a goal.
+pub struct Found<S: State> {
+    pub cost: S::Cost,
+    /// The states from the start to the goal, both included.
+    pub path: Vec<S>,
+}
+
+impl<S: State> Found<S> {
+    pub fn goal(&self) -> &S {
+        self.path.last().expect("a path holds at least its start")
+    }
+}
+
+/// The result of a search: the cheapest path to a goal if there is one, and what it took.
+pub struct Outcome<S: State> {
+    pub found: Option<Found<S>>,
+    pub stats: Stats,
+}
+
+/// An entry of the frontier: cheapest estimate first, then the furthest along, then the first
+/// queued.
+type Entry<C> = (Reverse<C>, C, Reverse<usize>);
+
+struct Node<S: State> {
+    state: S,
+    cost: S::Cost,
+    parent: Option<usize>,
+}
+
+/// A search under way, which can be run a few states at a time.
+pub struct Search<S: State> {
+    nodes: Vec<Node<S>>,
+    best: HashMap<S::Key, S::Cost>,
+    frontier: BinaryHeap<Entry<S::Cost>>,
+    stats: Stats,
+}
+
+impl<S: State> Search<S> {
+    pub fn new(starts: impl IntoIterator<Item = S>) -> Self {
+        let mut search = Self {
+            nodes: vec![],
+            best: HashMap::new(),
+            frontier: BinaryHeap::new(),
+            stats: Stats::default(),
+        };
+        for start in starts {
+            search.queue(start, S::Cost::default(), None);
+        }
+        search.stats.max_frontier = search.frontier.len();
+        search
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// How many states wait to be expanded, counting the stale ones.
+    pub fn frontier_len(&self) -> usize {
+        self.frontier.len()
+    }
+
+    /// The state to be expanded next, with the cost of reaching it.
+    pub fn peek(&self) -> Option<(&S, S::Cost)> {
+        self.frontier.peek().map(|&(_, cost, Reverse(index))| (&self.nodes[index].state, cost))
+    }
+
+    /// Takes at most `budget` states off the frontier, returning the outcome once the search is
+    /// over. Afterwards the search is empty and finds nothing more.
+    pub fn advance(&mut self, budget: usize) -> Option<Outcome<S>> {
+        for _ in 0..budget {
+            let Some((_, cost, Reverse(index))) = self.frontier.pop() else {
+                self.stats.trace(false);
+                return Some(Outcome { found: None, stats: self.stats });
+            };
+            let state = &self.nodes[index].state;
+            if self.best.get(&state.key()).is_some_and(|&best| best < cost) {
+                self.stats.stale += 1;
+                continue;
+            }
+            if state.is_goal() {
+                self.frontier.clear();
+                self.best.clear();
+                self.stats.trace(true);
+                let path = path(std::mem::take(&mut self.nodes), index);
+                return Some(Outcome { found: Some(Found { cost, path }), stats: self.stats });
+            }
+
+            self.stats.expanded += 1;
+            for (next, step) in state.successors() {
+                self.queue(next, cost + step, Some(index));
+            }
+            self.stats.max_frontier = self.stats.max_frontier.max(self.frontier.len());
+        }
+        None
+    }
+
+    /// Records that `state` can be reached for `cost`, and queues it unless it was already
+    /// reached for less.
+    fn queue(&mut self, state: S, cost: S::Cost, parent: Option<usize>) {
+        match self.best.entry(state.key()) {
+            hash_map::Entry::Occupied(entry) if *entry.get() <= cost => return,
+            hash_map::Entry::Occupied(mut entry) => *entry.get_mut() = cost,
+            hash_map::Entry::Vacant(entry) => {
+                entry.insert(cost);
+            }
+        }
+        let estimate = cost + state.heuristic();
+        self.nodes.push(Node { state, cost, parent });
+        self.frontier.push((Reverse(estimate), cost, Reverse(self.nodes.len() - 1)));
+        self.stats.queued += 1;
+    }
+}
+
+/// States taken off the frontier between two checks for cancellation or looks at the clock.
+const BATCH: usize = 100;
+
+/// Finds the cheapest path from any of `starts` to a goal, with Dijkstra's algorithm, or A* when
+/// the states have a heuristic. Gives up with `Error::TimedOut` when the run is cancelled.
+pub fn search<S: State>(starts: impl IntoIterator<Item = S>) -> common::Result<Outcome<S>> {
+    let _span = tracing::debug_span!("search").entered();
+    let mut search = Search::new(starts);
+    loop {
+        common::cancel::check()?;
+        if let Some(outcome) = search.advance(BATCH) {
+            return Ok(outcome);
+        }
+    }
+}
+
+/// The states leading to `nodes[index]`, which it takes out of `nodes`.
+fn path<S: State>(nodes: Vec<Node<S>>, mut index: usize) -> Vec<S> {
+    let mut indices = vec![index];
+    while let Some(parent) = nodes[index].parent {
+        indices.push(parent);
+        index = parent;
+    }
+
+    let mut nodes = nodes.into_iter().map(Some).collect::<Vec<_>>();
+    indices
+        .iter()
+        .rev()
+        .map(|&i| nodes[i].take().expect("a path visits a node once").state)
+        .collect()
+}
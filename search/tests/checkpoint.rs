@@ -0,0 +1,94 @@
+use search::{search, Checkpoint, Search, State};
+
+/// Walking along a line of cells, each costing its weight to enter, towards the last one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Walk<'a> {
+    weights: &'a [u32],
+    at: usize,
+}
+
+impl State for Walk<'_> {
+    type Key = usize;
+    type Cost = u32;
+
+    fn key(&self) -> usize {
+        self.at
+    }
+
+    fn successors(&self) -> Vec<(Self, u32)> {
+        [self.at.checked_sub(1), Some(self.at + 1), Some(self.at + 2)]
+            .into_iter()
+            .flatten()
+            .filter(|&at| at < self.weights.len())
+            .map(|at| (Walk { at, ..*self }, self.weights[at]))
+            .collect()
+    }
+
+    fn is_goal(&self) -> bool {
+        self.at == self.weights.len() - 1
+    }
+}
+
+impl Checkpoint for Walk<'_> {
+    type Saved = usize;
+
+    fn save(&self) -> usize {
+        self.at
+    }
+
+    fn restore(&self, at: usize) -> Self {
+        Walk { at, ..*self }
+    }
+
+    fn describe(&self) -> String {
+        format!("cell {}", self.at)
+    }
+}
+
+const WEIGHTS: &[u32] = &[0, 5, 1, 9, 1, 1, 8, 2];
+
+#[test]
+fn resumes_where_it_was_saved() {
+    let start = Walk { weights: WEIGHTS, at: 0 };
+    let whole = search([start]).unwrap();
+
+    let mut interrupted = Search::new([start]);
+    assert!(interrupted.advance(3).is_none());
+    let mut checkpoint = vec![];
+    interrupted.save(7, &mut checkpoint).unwrap();
+
+    let mut resumed = Search::load(checkpoint.as_slice(), 7, &start).unwrap();
+    assert_eq!(resumed.stats(), interrupted.stats());
+    let outcome = resumed.advance(usize::MAX).unwrap();
+    let (found, whole_found) = (outcome.found.unwrap(), whole.found.unwrap());
+    assert_eq!(found.cost, whole_found.cost);
+    assert_eq!(found.path, whole_found.path);
+    assert_eq!(outcome.stats, whole.stats);
+}
+
+#[test]
+fn refuses_another_search() {
+    let start = Walk { weights: WEIGHTS, at: 0 };
+    let mut checkpoint = vec![];
+    Search::new([start]).save(7, &mut checkpoint).unwrap();
+
+    assert!(Search::load(checkpoint.as_slice(), 8, &start).is_err());
+    assert!(Search::load(checkpoint.as_slice(), 7, &Walk { at: 1, ..start }).is_err());
+    assert!(Search::load(&checkpoint[..checkpoint.len() / 2], 7, &start).is_err());
+}
+
+#[test]
+fn refuses_corrupt_parents() {
+    let start = Walk { weights: WEIGHTS, at: 0 };
+    let mut search = Search::new([start]);
+    assert!(search.advance(3).is_none());
+    let mut checkpoint = vec![];
+    search.save(7, &mut checkpoint).unwrap();
+
+    for parent in [1, 99] {
+        let mut saved: serde_json::Value = serde_json::from_slice(&checkpoint).unwrap();
+        saved["nodes"][1][2] = parent.into();
+        let corrupt = serde_json::to_vec(&saved).unwrap();
+        assert!(Search::load(corrupt.as_slice(), 7, &start).is_err(), "{}", parent);
+    }
+}
@@ -0,0 +1,74 @@
+use search::{search, State};
+
+/// Walking along a line of cells, each costing its weight to enter, towards the last one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Walk<'a> {
+    weights: &'a [u32],
+    at: usize,
+    /// Whether to guess the remaining cost.
+    informed: bool,
+}
+
+impl State for Walk<'_> {
+    type Key = usize;
+    type Cost = u32;
+
+    fn key(&self) -> usize {
+        self.at
+    }
+
+    fn successors(&self) -> Vec<(Self, u32)> {
+        [self.at.checked_sub(1), Some(self.at + 1), Some(self.at + 2)]
+            .into_iter()
+            .flatten()
+            .filter(|&at| at < self.weights.len())
+            .map(|at| (Walk { at, ..*self }, self.weights[at]))
+            .collect()
+    }
+
+    fn is_goal(&self) -> bool {
+        self.at == self.weights.len() - 1
+    }
+
+    fn heuristic(&self) -> u32 {
+        if self.informed {
+            (self.weights.len() - 1 - self.at).div_ceil(2) as u32
+        } else {
+            0
+        }
+    }
+}
+
+const WEIGHTS: &[u32] = &[0, 5, 1, 9, 1, 1, 8, 2];
+
+#[test]
+fn cheapest_path() {
+    let outcome = search([Walk { weights: WEIGHTS, at: 0, informed: false }]).unwrap();
+    let found = outcome.found.unwrap();
+    assert_eq!(found.cost, 5);
+    assert_eq!(found.path.iter().map(|walk| walk.at).collect::<Vec<_>>(), [0, 2, 4, 5, 7]);
+    assert_eq!(found.goal().at, 7);
+}
+
+#[test]
+fn heuristic_keeps_the_cost_and_saves_work() {
+    let dijkstra = search([Walk { weights: WEIGHTS, at: 0, informed: false }]).unwrap();
+    let a_star = search([Walk { weights: WEIGHTS, at: 0, informed: true }]).unwrap();
+    assert_eq!(a_star.found.unwrap().cost, dijkstra.found.unwrap().cost);
+    assert!(a_star.stats.expanded <= dijkstra.stats.expanded);
+}
+
+#[test]
+fn start_at_the_goal() {
+    let outcome = search([Walk { weights: &[3], at: 0, informed: false }]).unwrap();
+    assert_eq!(outcome.found.unwrap().cost, 0);
+    assert_eq!(outcome.stats.expanded, 0);
+    assert_eq!(outcome.stats.queued, 1);
+}
+
+#[test]
+fn unreachable_goal() {
+    let outcome = search(Vec::<Walk<'_>>::new()).unwrap();
+    assert!(outcome.found.is_none());
+    assert_eq!(outcome.stats, Default::default());
+}